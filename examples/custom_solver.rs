@@ -0,0 +1,37 @@
+use advent_of_code::{AocError, ParseError, SolveError, Solver};
+
+struct Depths {}
+
+impl Solver for Depths {
+    type Input = Vec<u64>;
+    type Output = usize;
+    const DAY: u8 = 1;
+
+    fn parse(input: String) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| line.parse().map_err(ParseError::from))
+            .collect()
+    }
+
+    fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Ok(input.windows(2).filter(|pair| pair[0] < pair[1]).count())
+    }
+}
+
+fn main() {
+    let input = String::from("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n");
+
+    for (part, result) in [
+        (1, advent_of_code::solve_part_1::<Depths>(input.clone())),
+        (2, advent_of_code::solve_part_2::<Depths>(input)),
+    ] {
+        match result {
+            Ok(answer) => println!("Part {part}: {answer}"),
+            Err(AocError::Solving(SolveError::Unimplemented)) => {
+                println!("Part {part} is not implemented")
+            }
+            Err(e) => eprintln!("Part {part} failed: {e}"),
+        }
+    }
+}
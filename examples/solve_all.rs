@@ -0,0 +1,25 @@
+use std::fs;
+
+fn main() {
+    for day in advent_of_code::days() {
+        let path = day.id().input_path();
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {}: {}: {}", day.id(), path.display(), e);
+                continue;
+            }
+        };
+
+        for (part, result) in [
+            (1, day.solve_part_1(input.clone())),
+            (2, day.solve_part_2(input)),
+        ] {
+            match result {
+                Ok(answer) => println!("{} part {}: {}", day.id(), part, answer),
+                Err(e) => eprintln!("{} part {}: {}", day.id(), part, e),
+            }
+        }
+    }
+}
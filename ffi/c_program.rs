@@ -0,0 +1,32 @@
+#![cfg(target_os = "linux")]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+
+    // Cargo builds the cdylib next to the test executables.
+    let lib_dir = env::current_exe().unwrap().parent().unwrap().to_owned();
+    let program = out_dir.join("test_aoc");
+    let compiler = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+
+    let status = Command::new(compiler)
+        .arg(manifest_dir.join("ffi/test_aoc.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-ladvent_of_code", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile ffi/test_aoc.c");
+
+    let status = Command::new(&program).status().unwrap();
+    assert!(status.success(), "ffi/test_aoc.c failed");
+}
@@ -0,0 +1,47 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void check(uint8_t day, uint8_t part, const char *input, AocStatus status, const char *value)
+{
+    AocAnswer answer = aoc_solve(day, part, (const uint8_t *)input, strlen(input));
+
+    if (answer.status != status) {
+        fprintf(stderr, "day %d part %d: expected status %d, got %d (%s)\n", day, part, status,
+                answer.status, answer.value ? answer.value : "(null)");
+        failures++;
+    } else if (value != NULL && (answer.value == NULL || strcmp(answer.value, value) != 0)) {
+        fprintf(stderr, "day %d part %d: expected \"%s\", got \"%s\"\n", day, part, value,
+                answer.value ? answer.value : "(null)");
+        failures++;
+    }
+
+    aoc_free(answer);
+}
+
+int main(void)
+{
+    const char *calories = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    check(1, 1, calories, AOC_OK, "24000");
+    check(1, 2, calories, AOC_OK, "45000");
+    check(25, 1, "1=\n12", AOC_OK, "20");
+    check(25, 2, "1=-0-2", AOC_UNIMPLEMENTED, NULL);
+    check(1, 1, "not a number", AOC_PARSE_ERROR, NULL);
+    check(0, 1, calories, AOC_UNKNOWN_DAY, NULL);
+    check(1, 3, calories, AOC_UNKNOWN_PART, NULL);
+    check(1, 1, "\xff", AOC_INVALID_UTF8, NULL);
+
+    AocAnswer answer = aoc_solve(1, 1, NULL, 4);
+    if (answer.status != AOC_INVALID_ARGUMENT) {
+        fprintf(stderr, "null input: expected status %d, got %d\n", AOC_INVALID_ARGUMENT,
+                answer.status);
+        failures++;
+    }
+    aoc_free(answer);
+
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,59 @@
+/* Generated by advent_of_code::ffi::header(). Do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum AocStatus {
+    AOC_OK = 0,
+    AOC_INVALID_ARGUMENT = 1,
+    AOC_INVALID_UTF8 = 2,
+    AOC_UNKNOWN_DAY = 3,
+    AOC_UNKNOWN_PART = 4,
+    AOC_INPUT_ERROR = 5,
+    AOC_PARSE_ERROR = 6,
+    AOC_SOLVE_ERROR = 7,
+    AOC_UNIMPLEMENTED = 8,
+    AOC_PANIC = 9,
+} AocStatus;
+
+typedef struct AocAnswer {
+    int32_t status;
+    char *value;
+} AocAnswer;
+
+AocAnswer aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t input_len);
+
+void aoc_free(AocAnswer answer);
+
+#define AOC_PLUGIN_ABI 2
+
+typedef struct AocPlugin {
+    uint32_t abi_version;
+    uint16_t year;
+    uint8_t day;
+    const uint8_t *name_ptr;
+    size_t name_len;
+    uint32_t version;
+    uint8_t parts;
+    AocAnswer (*parse)(const uint8_t *input_ptr, size_t input_len, void **parsed);
+    AocAnswer (*solve)(void *parsed, uint8_t part);
+    AocAnswer (*summarise)(const void *parsed);
+    void (*drop)(void *parsed);
+    void (*free)(AocAnswer answer);
+} AocPlugin;
+
+/* Exported by plugins rather than by this library. */
+const AocPlugin *aoc_plugins(size_t *count);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
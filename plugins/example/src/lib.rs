@@ -0,0 +1,78 @@
+//! An example plugin, loaded by the tests of the plugin interface. It solves a
+//! puzzle the library does not know, and adds a variant of one it does.
+
+use advent_of_code::{ParseError, SolveError, Solver};
+
+// 2021 day 1: counts how often the depth increases.
+pub struct SonarSweep {}
+
+impl Solver for SonarSweep {
+    type Input = Vec<u32>;
+    type Output = usize;
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+
+    fn parse(input: String) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| Ok(line.parse()?)).collect()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!("{} depths", input.len())
+    }
+
+    fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Ok(increases(&input, 1))
+    }
+
+    fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Ok(increases(&input, 3))
+    }
+}
+
+// Comparing sliding windows of this size only needs the depths that differ.
+fn increases(depths: &[u32], window: usize) -> usize {
+    depths
+        .iter()
+        .zip(depths.iter().skip(window))
+        .filter(|(before, after)| after > before)
+        .count()
+}
+
+// 2022 day 1, sorting every elf instead of keeping the top three.
+pub struct Calories {}
+
+impl Solver for Calories {
+    type Input = Vec<Vec<u32>>;
+    type Output = u32;
+    const DAY: u8 = 1;
+    const NAME: &'static str = "plugin";
+
+    fn parse(input: String) -> Result<Self::Input, ParseError> {
+        input
+            .split("\n\n")
+            .map(|elf| elf.lines().map(|line| Ok(line.parse()?)).collect())
+            .collect()
+    }
+
+    fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
+        top(input, 1)
+    }
+
+    fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
+        top(input, 3)
+    }
+}
+
+fn top(input: Vec<Vec<u32>>, count: usize) -> Result<u32, SolveError> {
+    let mut totals: Vec<u32> = input.iter().map(|elf| elf.iter().sum()).collect();
+
+    if totals.len() < count {
+        return Err(SolveError::InvalidInput);
+    }
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+
+    Ok(totals[..count].iter().sum())
+}
+
+advent_of_code::export_plugins!(SonarSweep, Calories);
@@ -0,0 +1,40 @@
+#![cfg(target_os = "linux")]
+
+use std::env;
+
+use advent_of_code::plugin::{load, load_dir};
+use advent_of_code::{
+    days, get_implementations, get_puzzle, years, AocError, ParseError, PuzzleId,
+};
+
+#[test]
+fn plugin() {
+    // Cargo builds the cdylib next to the test executables.
+    let dir = env::current_exe().unwrap().parent().unwrap().to_owned();
+
+    assert_eq!(load(&dir.join("libexample_plugin.so")).unwrap(), 2);
+
+    let sonar_sweep = get_puzzle(PuzzleId::new(2021, 1)).unwrap();
+    let input = String::from("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n");
+
+    assert!(sonar_sweep.is_plugin());
+    assert_eq!(sonar_sweep.solve_part_1(input.clone()).unwrap(), "7");
+    assert_eq!(sonar_sweep.solve_part_2(input.clone()).unwrap(), "5");
+    assert_eq!(sonar_sweep.summarise(input).unwrap(), "10 depths");
+    assert!(matches!(
+        sonar_sweep.solve_part_1(String::from("deep\n")),
+        Err(AocError::Parsing(ParseError::Plugin(_)))
+    ));
+
+    assert_eq!(days().len(), 26);
+    assert_eq!(years(), vec![2021, 2022]);
+
+    let calories = get_implementations(PuzzleId::new(2022, 1));
+    let names: Vec<_> = calories.iter().map(|day| day.name()).collect();
+    let input = String::from("1000\n2000\n\n4000\n\n5000\n\n6000\n");
+
+    assert_eq!(names, vec!["reference", "plugin"]);
+    assert_eq!(calories[1].solve_part_2(input).unwrap(), "15000");
+
+    assert!(matches!(load_dir(&dir.join("no such directory")), Ok(0)));
+}
@@ -0,0 +1,136 @@
+// Arithmetic on numbers that come from puzzle inputs, which never panics or
+// wraps. With the `checked` feature, which is on by default, overflow and
+// division by zero return a SolveError naming the operation. Without it they
+// are reported as invalid input.
+
+// Which of these are used depends on the days that are enabled.
+#![allow(dead_code)]
+
+use crate::SolveError;
+
+const CHECKED: bool = cfg!(feature = "checked");
+
+pub(crate) trait Number: Copy + PartialEq {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$t>::checked_div(self, other)
+                }
+
+                fn checked_pow(self, exponent: u32) -> Option<Self> {
+                    <$t>::checked_pow(self, exponent)
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(i64, u64, usize);
+
+pub(crate) fn add<T: Number>(a: T, b: T, operation: &'static str) -> Result<T, SolveError> {
+    a.checked_add(b).ok_or_else(|| overflow(operation))
+}
+
+pub(crate) fn sub<T: Number>(a: T, b: T, operation: &'static str) -> Result<T, SolveError> {
+    a.checked_sub(b).ok_or_else(|| overflow(operation))
+}
+
+pub(crate) fn mul<T: Number>(a: T, b: T, operation: &'static str) -> Result<T, SolveError> {
+    a.checked_mul(b).ok_or_else(|| overflow(operation))
+}
+
+pub(crate) fn div<T: Number>(a: T, b: T, operation: &'static str) -> Result<T, SolveError> {
+    if b == T::ZERO {
+        Err(if CHECKED {
+            SolveError::DivisionByZero(operation)
+        } else {
+            SolveError::InvalidInput
+        })
+    } else {
+        a.checked_div(b).ok_or_else(|| overflow(operation))
+    }
+}
+
+pub(crate) fn pow<T: Number>(
+    base: T,
+    exponent: u32,
+    operation: &'static str,
+) -> Result<T, SolveError> {
+    base.checked_pow(exponent)
+        .ok_or_else(|| overflow(operation))
+}
+
+fn overflow(operation: &'static str) -> SolveError {
+    if CHECKED {
+        SolveError::Overflow(operation)
+    } else {
+        SolveError::InvalidInput
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The error a failed operation returns in this build.
+    fn error(checked: SolveError) -> String {
+        if CHECKED {
+            checked.to_string()
+        } else {
+            SolveError::InvalidInput.to_string()
+        }
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(add(2i64, 3, "sum").unwrap(), 5);
+        assert_eq!(pow(5i64, 3, "power").unwrap(), 125);
+        assert_eq!(div(7i64, -2, "a / b").unwrap(), -3);
+
+        assert_eq!(
+            mul(u64::MAX, 2, "old * old").unwrap_err().to_string(),
+            error(SolveError::Overflow("old * old"))
+        );
+        assert_eq!(
+            sub(1usize, 2, "space to free").unwrap_err().to_string(),
+            error(SolveError::Overflow("space to free"))
+        );
+        assert_eq!(
+            div(1i64, 0, "a / b").unwrap_err().to_string(),
+            error(SolveError::DivisionByZero("a / b"))
+        );
+        assert_eq!(
+            div(i64::MIN, -1, "a / b").unwrap_err().to_string(),
+            error(SolveError::Overflow("a / b"))
+        );
+        assert_eq!(
+            pow(5i64, 40, "5^40").unwrap_err().to_string(),
+            error(SolveError::Overflow("5^40"))
+        );
+    }
+}
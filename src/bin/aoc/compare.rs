@@ -0,0 +1,190 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use advent_of_code::{get_implementations, AocError, Day};
+
+use crate::run::select;
+use crate::CliError;
+
+struct Options {
+    days: Vec<Day>,
+    parts: Vec<u8>,
+    input: Option<PathBuf>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, CliError> {
+    let mut options = Options {
+        days: vec![],
+        parts: vec![1, 2],
+        input: None,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => match args.next().map(|part| part.as_str()) {
+                Some("1") => options.parts = vec![1],
+                Some("2") => options.parts = vec![2],
+                _ => return Err(CliError::Usage),
+            },
+            "--input" => {
+                options.input = Some(PathBuf::from(args.next().ok_or(CliError::Usage)?));
+            }
+            _ => options.days.extend(select(arg)?),
+        }
+    }
+
+    if options.days.is_empty() || (options.input.is_some() && options.days.len() > 1) {
+        Err(CliError::Usage)
+    } else {
+        Ok(options)
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let options = parse_args(args)?;
+    let mut disagreements = 0;
+    let mut unreadable = 0;
+
+    for day in options.days.iter() {
+        let path = options
+            .input
+            .clone()
+            .unwrap_or_else(|| day.id().input_path());
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{}: {}: {}", day.id(), path.display(), e);
+                unreadable += 1;
+                continue;
+            }
+        };
+
+        let implementations = get_implementations(day.id());
+
+        for &part in options.parts.iter() {
+            let results = compare(&implementations, part, &input);
+
+            println!("{} part {}:", day.id(), part);
+            print!("{}", report(&results));
+
+            disagreements += results
+                .iter()
+                .filter(|result| !agrees(&results[0], result))
+                .count();
+        }
+    }
+
+    if unreadable > 0 {
+        Err(CliError::Unreadable(unreadable))
+    } else if disagreements > 0 {
+        Err(CliError::Disagreement(disagreements))
+    } else {
+        Ok(())
+    }
+}
+
+struct Timed {
+    name: &'static str,
+    answer: Result<String, AocError>,
+    elapsed: Duration,
+}
+
+// Solves a part with every implementation, the reference first.
+fn compare(implementations: &[Day], part: u8, input: &str) -> Vec<Timed> {
+    implementations
+        .iter()
+        .map(|day| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => day.solve_part_1(input.to_owned()),
+                _ => day.solve_part_2(input.to_owned()),
+            };
+
+            Timed {
+                name: day.name(),
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+fn agrees(reference: &Timed, result: &Timed) -> bool {
+    match (&reference.answer, &result.answer) {
+        (Ok(expected), Ok(answer)) => expected == answer,
+        (Err(_), Err(_)) => true,
+        _ => false,
+    }
+}
+
+fn report(results: &[Timed]) -> String {
+    let reference = &results[0];
+
+    results
+        .iter()
+        .map(|result| {
+            let answer = match &result.answer {
+                Ok(answer) => answer.clone(),
+                Err(e) => e.to_string(),
+            };
+            let speedup = reference.elapsed.as_secs_f64() / result.elapsed.as_secs_f64();
+            let verdict = if agrees(reference, result) {
+                ""
+            } else {
+                " (disagrees)"
+            };
+
+            format!(
+                "  {:<12} {:>10.3} ms {:>7.2}x  {}{}\n",
+                result.name,
+                result.elapsed.as_secs_f64() * 1000.0,
+                speedup,
+                answer,
+                verdict
+            )
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use advent_of_code::PuzzleId;
+
+    use super::*;
+    use crate::ExitCode;
+
+    #[test]
+    fn comparison() {
+        let implementations = get_implementations(PuzzleId::new(2022, 20));
+        let results = compare(&implementations, 2, "1\n2\n-3\n3\n-2\n0\n4\n");
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| agrees(&results[0], result)));
+        assert_eq!(results[1].answer.as_ref().unwrap(), "1623178306");
+
+        let disagreeing = Timed {
+            name: "broken",
+            answer: Ok(String::from("0")),
+            elapsed: Duration::from_millis(1),
+        };
+
+        assert!(!agrees(&results[0], &disagreeing));
+        assert!(report(&[disagreeing]).contains("broken"));
+    }
+
+    #[test]
+    fn missing_input() {
+        let args: Vec<_> = ["2022/20", "--input", "no such file"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let result = run(&args);
+
+        assert!(matches!(result, Err(CliError::Unreadable(1))));
+        assert_eq!(result.unwrap_err().exit_code(), ExitCode::Input);
+    }
+}
@@ -0,0 +1,39 @@
+use advent_of_code::{implementations, Day};
+
+use crate::CliError;
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    if !args.is_empty() {
+        return Err(CliError::Usage);
+    }
+
+    for day in implementations() {
+        println!("{}", describe(&day));
+    }
+
+    Ok(())
+}
+
+fn describe(day: &Day) -> String {
+    format!(
+        "{:<8} {:<12} v{}{}",
+        day.id().to_string(),
+        day.name(),
+        day.version(),
+        if day.is_plugin() { " (plugin)" } else { "" }
+    )
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use advent_of_code::{get_puzzle, PuzzleId};
+
+    use super::*;
+
+    #[test]
+    fn description() {
+        let day = get_puzzle(PuzzleId::new(2022, 20)).unwrap();
+
+        assert_eq!(describe(&day), "2022/20  reference    v1");
+    }
+}
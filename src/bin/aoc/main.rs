@@ -0,0 +1,198 @@
+mod compare;
+mod list;
+mod minimise;
+mod repl;
+mod report;
+mod run;
+mod serve;
+mod status;
+mod validate;
+mod watch;
+
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::process;
+
+use advent_of_code::{
+    log, parallel, plugin, set_default_policy, AocError, NormalisePolicy, PuzzleId, SolveError,
+};
+
+const USAGE: &str = "Usage:
+  aoc run <puzzle>.. [--part <part>] [--input <file>] [--timeout <seconds>]
+          [--no-cache] [--clear-cache]
+  aoc list
+  aoc validate <puzzle>.. [--input <file>]
+  aoc compare <puzzle>.. [--part <part>] [--input <file>]
+  aoc minimise <puzzle> --input <file> [--part <part>] [--output <file>] <failure>
+  aoc report [<puzzle>..] [--timings [--runs <count>]] [--output <file>]
+  aoc serve [--port <port>]
+  aoc status [<year>]
+  aoc watch <puzzle> [--input <file>].. [--interval <milliseconds>]
+  aoc repl
+
+Options for every command:
+  -v, --verbose      log more, repeat for even more
+  -q, --quiet        only log errors
+  --log <target>     only log from a module, e.g. day_19
+  --threads <count>  solve on at most this many threads, 0 for one per CPU
+
+A failure is --panic, --error <text>, --mismatch or --slower <milliseconds>.
+A puzzle is <year>/<day>, <year> for all its days, or <day> for the latest year.
+Plugins are loaded from .aoc_plugins, or from $AOC_PLUGIN_DIR if it is set.
+Watching polls the input and example of a puzzle, or the given inputs.
+A report only changes with the answers, unless --timings adds median times,
+which are rounded but still vary from run to run.
+Answers are cached in .aoc_cache, or in $AOC_CACHE_DIR if it is set.
+Inputs are normalised as set by $AOC_NORMALISE: off, quiet, warn (default) or strict.
+$AOC_LOG sets the initial log level: error, warn (default), info, debug or trace.
+
+Exit codes:
+  0  success            5  solve error
+  1  other error        6  unimplemented part
+  2  usage              7  timeout
+  3  input error        8  wrong answer
+  4  parse error
+After several failures, the first one sets the exit code.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = configure(args).and_then(|args| {
+        plugin::load_dir(&plugin::default_dir()).map_err(AocError::from)?;
+        execute(&args)
+    });
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(e.exit_code() as i32);
+    }
+}
+
+fn execute(args: &[String]) -> Result<(), CliError> {
+    match args.first().map(|arg| arg.as_str()) {
+        Some("run") => run::run(&args[1..]),
+        Some("list") => list::run(&args[1..]),
+        Some("validate") => validate::run(&args[1..]),
+        Some("compare") => compare::run(&args[1..]),
+        Some("minimise") => minimise::run(&args[1..]),
+        Some("report") => report::run(&args[1..]),
+        Some("serve") => serve::run(&args[1..]),
+        Some("status") => status::run(&args[1..]),
+        Some("watch") => watch::run(&args[1..]),
+        Some("repl") => repl::run().map_err(CliError::from),
+        _ => Err(CliError::Usage),
+    }
+}
+
+// Applies the options for every command and returns the rest.
+fn configure(args: Vec<String>) -> Result<Vec<String>, CliError> {
+    let mut level = log::Level::from_env()
+        .map_err(AocError::from)?
+        .unwrap_or_else(log::level);
+    let mut targets = vec![];
+    let mut remaining = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => level = level.more_verbose(),
+            "-q" | "--quiet" => level = log::Level::Error,
+            "--log" => targets.push(args.next().ok_or(CliError::Usage)?),
+            "--threads" => match args.next().map(|count| count.parse()) {
+                Some(Ok(count)) => parallel::set_threads(count),
+                _ => return Err(CliError::Usage),
+            },
+            _ => remaining.push(arg),
+        }
+    }
+
+    log::set_level(level);
+    log::set_targets(targets);
+    set_default_policy(NormalisePolicy::Warn);
+
+    Ok(remaining)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExitCode {
+    Error = 1,
+    Usage = 2,
+    Input = 3,
+    Parse = 4,
+    Solve = 5,
+    Unimplemented = 6,
+    Timeout = 7,
+    Mismatch = 8,
+}
+
+impl From<&AocError> for ExitCode {
+    fn from(e: &AocError) -> Self {
+        match e {
+            AocError::Input(_) => ExitCode::Input,
+            AocError::Parsing(_) => ExitCode::Parse,
+            AocError::Solving(SolveError::Unimplemented) => ExitCode::Unimplemented,
+            AocError::Solving(_) | AocError::Plugin(_) => ExitCode::Solve,
+            _ => ExitCode::Error,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    Aoc(AocError),
+    Disagreement(usize),
+    Failed(run::Summary),
+    Io(io::Error),
+    Invalid(usize),
+    NoFailure,
+    UnknownPuzzle(PuzzleId),
+    Unreadable(usize),
+    Usage,
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            CliError::Aoc(e) => ExitCode::from(e),
+            CliError::Disagreement(_) => ExitCode::Mismatch,
+            CliError::Failed(summary) => summary.exit_code().unwrap_or(ExitCode::Error),
+            CliError::Invalid(_) | CliError::Unreadable(_) => ExitCode::Input,
+            CliError::Usage => ExitCode::Usage,
+            CliError::Io(_) | CliError::NoFailure | CliError::UnknownPuzzle(_) => ExitCode::Error,
+        }
+    }
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CliError::Aoc(e) => e.fmt(f),
+            CliError::Disagreement(count) => {
+                write!(f, "{} answers disagree with the reference", count)
+            }
+            CliError::Failed(summary) => match summary.failures() {
+                1 => write!(f, "1 part failed"),
+                count => write!(f, "{} parts failed", count),
+            },
+            CliError::Io(e) => e.fmt(f),
+            CliError::Invalid(count) => write!(f, "{} inputs failed validation", count),
+            CliError::NoFailure => write!(f, "The input does not fail"),
+            CliError::UnknownPuzzle(id) => write!(f, "No solver for {}", id),
+            CliError::Unreadable(count) => write!(f, "{} inputs could not be read", count),
+            CliError::Usage => write!(f, "{}", USAGE),
+        }
+    }
+}
+
+impl From<AocError> for CliError {
+    fn from(e: AocError) -> Self {
+        CliError::Aoc(e)
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(e: io::Error) -> Self {
+        CliError::Io(e)
+    }
+}
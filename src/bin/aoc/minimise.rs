@@ -0,0 +1,123 @@
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code::{log, minimise, AocError, Failure, PuzzleId};
+
+use crate::CliError;
+
+struct Options {
+    id: PuzzleId,
+    part: u8,
+    input: PathBuf,
+    output: Option<PathBuf>,
+    failure: Failure,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, CliError> {
+    let mut id = None;
+    let mut part = 1;
+    let mut input = None;
+    let mut output = None;
+    let mut failure = None;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => match args.next().map(|part| part.as_str()) {
+                Some("1") => part = 1,
+                Some("2") => part = 2,
+                _ => return Err(CliError::Usage),
+            },
+            "--input" => input = Some(PathBuf::from(args.next().ok_or(CliError::Usage)?)),
+            "--output" => output = Some(PathBuf::from(args.next().ok_or(CliError::Usage)?)),
+            "--panic" => failure = Some(Failure::Panic),
+            "--error" => {
+                failure = Some(Failure::Error(args.next().ok_or(CliError::Usage)?.clone()))
+            }
+            "--mismatch" => failure = Some(Failure::Mismatch),
+            "--slower" => match args.next().map(|millis| millis.parse()) {
+                Some(Ok(millis)) => failure = Some(Failure::Slower(Duration::from_millis(millis))),
+                _ => return Err(CliError::Usage),
+            },
+            _ if id.is_none() => id = Some(arg.parse::<PuzzleId>().map_err(AocError::from)?),
+            _ => return Err(CliError::Usage),
+        }
+    }
+
+    match (id, input, failure) {
+        (Some(id), Some(input), Some(failure)) => Ok(Options {
+            id,
+            part,
+            input,
+            output,
+            failure,
+        }),
+        _ => Err(CliError::Usage),
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let options = parse_args(args)?;
+    let input = fs::read_to_string(&options.input)?;
+
+    // Candidates are expected to panic or be malformed, so keep their panics
+    // and log records from flooding stderr.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let (minimised, _) = log::capture(|| {
+        minimise(&input, |input| {
+            options.failure.holds(options.id, options.part, input)
+        })
+    });
+    panic::set_hook(hook);
+
+    let minimised = minimised.ok_or(CliError::NoFailure)?;
+    let output = options
+        .output
+        .clone()
+        .unwrap_or_else(|| options.input.with_extension("min"));
+    fs::write(&output, &minimised)?;
+
+    println!(
+        "Minimised {} from {} to {} lines in {}",
+        options.input.display(),
+        input.lines().count(),
+        minimised.lines().count(),
+        output.display()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn arguments() {
+        let options = parse_args(&args(&[
+            "2022/21", "--input", "in", "--part", "2", "--error", "invalid",
+        ]))
+        .unwrap();
+
+        assert_eq!(options.id, PuzzleId::new(2022, 21));
+        assert_eq!(options.part, 2);
+        assert_eq!(options.failure, Failure::Error(String::from("invalid")));
+
+        assert!(matches!(
+            parse_args(&args(&["21", "--input", "in"])),
+            Err(CliError::Usage)
+        ));
+        assert!(matches!(
+            parse_args(&args(&["21", "--input", "in", "--slower", "fast"])),
+            Err(CliError::Usage)
+        ));
+    }
+}
@@ -0,0 +1,379 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use advent_of_code::{get_puzzle, AocError, Day, Params, PuzzleId, Simulator};
+
+const HISTORY_FILE: &str = ".aoc_history";
+
+const HELP: &str = "Commands:
+  load <puzzle> [file]       load the input of a puzzle, e.g. 2022/17
+  summary                    summarise the parsed input
+  params                     list the parameters of the loaded day
+  run <part> [name=value]..  solve a part, optionally overriding parameters
+  step [rounds]              advance the simulation and show its state
+  show                       show the state of the simulation
+  reset                      restart the simulation
+  history                    list previous commands
+  !<n>                       repeat command <n> from the history
+  help                       show this message
+  quit                       leave the shell";
+
+pub fn run() -> io::Result<()> {
+    let mut history = History::open(history_path())?;
+    let mut repl = Repl::default();
+    let stdin = io::stdin();
+
+    loop {
+        print!("{}> ", repl.prompt());
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+
+        if stdin.lock().read_line(&mut line)? == 0 {
+            println!();
+            break;
+        }
+
+        let mut line = line.trim().to_owned();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(index) = line.strip_prefix('!') {
+            match index.parse().ok().and_then(|index| history.get(index)) {
+                Some(command) => {
+                    println!("{}", command);
+                    line = command.to_owned();
+                }
+                None => {
+                    eprintln!("No command {} in the history", index);
+                    continue;
+                }
+            }
+        }
+
+        history.push(&line)?;
+
+        match line.as_str() {
+            "quit" | "exit" => break,
+            "history" => {
+                for (index, command) in history.entries.iter().enumerate() {
+                    println!("{:>5}  {}", index + 1, command);
+                }
+            }
+            _ => match repl.execute(&line) {
+                Ok(output) => println!("{}", output.trim_end()),
+                Err(e) => eprintln!("{}", e),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+fn history_path() -> PathBuf {
+    env::var_os("AOC_HISTORY")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(HISTORY_FILE))
+}
+
+struct History {
+    path: PathBuf,
+    entries: Vec<String>,
+}
+
+impl History {
+    fn open(path: PathBuf) -> io::Result<Self> {
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) => contents.lines().map(|line| line.to_owned()).collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, entries })
+    }
+
+    fn get(&self, index: usize) -> Option<&str> {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.entries.get(index))
+            .map(|command| command.as_str())
+    }
+
+    fn push(&mut self, command: &str) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", command)?;
+
+        self.entries.push(command.to_owned());
+
+        Ok(())
+    }
+}
+
+struct Session {
+    day: Day,
+    input: String,
+    simulator: Option<Simulator>,
+}
+
+impl Session {
+    fn simulator(&mut self) -> Result<&mut Simulator, ReplError> {
+        if self.simulator.is_none() {
+            let simulator = self
+                .day
+                .simulate(self.input.clone())
+                .ok_or_else(|| ReplError::NotSimulated(self.day.id()))??;
+            self.simulator = Some(simulator);
+        }
+
+        Ok(self.simulator.as_mut().unwrap())
+    }
+}
+
+#[derive(Default)]
+pub struct Repl {
+    session: Option<Session>,
+}
+
+impl Repl {
+    pub fn prompt(&self) -> String {
+        match &self.session {
+            Some(session) => session.day.id().to_string(),
+            None => String::from("aoc"),
+        }
+    }
+
+    pub fn execute(&mut self, line: &str) -> Result<String, ReplError> {
+        let mut args = line.split_whitespace();
+        let command = args.next().unwrap_or("");
+        let args: Vec<_> = args.collect();
+
+        match (command, args.as_slice()) {
+            ("load", [puzzle]) => self.load(puzzle, None),
+            ("load", [puzzle, path]) => self.load(puzzle, Some(path)),
+            ("load", _) => Err(ReplError::Usage("load <puzzle> [file]")),
+            ("summary", []) => {
+                let session = self.session()?;
+                Ok(session.day.summarise(session.input.clone())?)
+            }
+            ("params", []) => self.params(),
+            ("run", [part, params @ ..]) => self.solve(part, params),
+            ("run", _) => Err(ReplError::Usage("run <part> [name=value]..")),
+            ("step", []) => self.step(1),
+            ("step", [rounds]) => match rounds.parse() {
+                Ok(rounds) => self.step(rounds),
+                Err(_) => Err(ReplError::Usage("step [rounds]")),
+            },
+            ("show", []) => self.step(0),
+            ("reset", []) => {
+                self.session_mut()?.simulator = None;
+                Ok(String::from("Simulation restarted"))
+            }
+            ("help", _) => Ok(String::from(HELP)),
+            _ => Err(ReplError::UnknownCommand(line.to_owned())),
+        }
+    }
+
+    fn session(&self) -> Result<&Session, ReplError> {
+        self.session.as_ref().ok_or(ReplError::NoInput)
+    }
+
+    fn session_mut(&mut self) -> Result<&mut Session, ReplError> {
+        self.session.as_mut().ok_or(ReplError::NoInput)
+    }
+
+    fn load(&mut self, puzzle: &str, path: Option<&str>) -> Result<String, ReplError> {
+        let day = puzzle
+            .parse()
+            .ok()
+            .and_then(get_puzzle)
+            .ok_or_else(|| ReplError::UnknownPuzzle(puzzle.to_owned()))?;
+        let path = path
+            .map(PathBuf::from)
+            .unwrap_or_else(|| day.id().input_path());
+        let input = fs::read_to_string(path)?;
+        let summary = day.summarise(input.clone())?;
+
+        self.session = Some(Session {
+            day,
+            input,
+            simulator: None,
+        });
+
+        Ok(format!("Loaded {}: {}", day.id(), summary))
+    }
+
+    fn params(&self) -> Result<String, ReplError> {
+        let day = self.session()?.day;
+
+        if day.params().is_empty() {
+            Ok(format!("{} has no parameters", day.id()))
+        } else {
+            Ok(day
+                .params()
+                .iter()
+                .map(|param| format!("{}: {}\n", param.name, param.description))
+                .collect())
+        }
+    }
+
+    fn solve(&self, part: &str, args: &[&str]) -> Result<String, ReplError> {
+        let session = self.session()?;
+        let mut params = Params::new();
+
+        for arg in args {
+            match arg
+                .split_once('=')
+                .map(|(name, value)| (name, value.parse()))
+            {
+                Some((name, Ok(value))) => params.set(name, value),
+                _ => return Err(ReplError::Usage("run <part> [name=value]..")),
+            }
+        }
+
+        let input = session.input.clone();
+
+        let answer = match part {
+            "1" => session.day.solve_part_1_with(input, &params)?,
+            "2" => session.day.solve_part_2_with(input, &params)?,
+            _ => return Err(ReplError::UnknownPart(part.to_owned())),
+        };
+
+        Ok(format!("Part {}: {}", part, answer))
+    }
+
+    fn step(&mut self, rounds: usize) -> Result<String, ReplError> {
+        let simulator = self.session_mut()?.simulator()?;
+
+        for _ in 0..rounds {
+            if !simulator.step() {
+                break;
+            }
+        }
+
+        let status = if simulator.is_finished() {
+            " (finished)"
+        } else {
+            ""
+        };
+
+        Ok(format!(
+            "Round {}{}\n{}",
+            simulator.round(),
+            status,
+            simulator.render()
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub enum ReplError {
+    Aoc(AocError),
+    Io(io::Error),
+    NoInput,
+    NotSimulated(PuzzleId),
+    UnknownCommand(String),
+    UnknownPuzzle(String),
+    UnknownPart(String),
+    Usage(&'static str),
+}
+
+impl Display for ReplError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ReplError::Aoc(e) => e.fmt(f),
+            ReplError::Io(e) => e.fmt(f),
+            ReplError::NoInput => write!(f, "No input loaded, use: load <puzzle> [file]"),
+            ReplError::NotSimulated(id) => write!(f, "{} has no simulation", id),
+            ReplError::UnknownCommand(command) => {
+                write!(f, "Unknown command '{}', try: help", command)
+            }
+            ReplError::UnknownPuzzle(puzzle) => write!(f, "Unknown puzzle {}", puzzle),
+            ReplError::UnknownPart(part) => write!(f, "Unknown part {}", part),
+            ReplError::Usage(usage) => write!(f, "Usage: {}", usage),
+        }
+    }
+}
+
+impl From<AocError> for ReplError {
+    fn from(e: AocError) -> Self {
+        ReplError::Aoc(e)
+    }
+}
+
+impl From<io::Error> for ReplError {
+    fn from(e: io::Error) -> Self {
+        ReplError::Io(e)
+    }
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use super::*;
+
+    fn load(day: u8, input: &str) -> Repl {
+        let path = env::temp_dir().join(format!("aoc_repl_day_{}", day));
+        fs::write(&path, input).unwrap();
+
+        let mut repl = Repl::default();
+        let command = format!("load {} {}", day, path.display());
+        repl.execute(&command).unwrap();
+
+        repl
+    }
+
+    #[test]
+    fn summary_and_run() {
+        let mut repl = load(20, "1\n2\n-3\n3\n-2\n0\n4\n");
+
+        assert_eq!(repl.prompt(), "2022/20");
+        assert_eq!(repl.execute("summary").unwrap(), "7 numbers from -3 to 4");
+        assert_eq!(repl.execute("run 1").unwrap(), "Part 1: 3");
+        assert_eq!(
+            repl.execute("run 1 key=811589153").unwrap(),
+            "Part 1: 4869534918"
+        );
+        assert!(repl.execute("run 1 speed=3").is_err());
+        assert!(repl.execute("run 3").is_err());
+    }
+
+    #[test]
+    fn step() {
+        let mut repl = load(17, ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n");
+
+        assert!(repl.execute("step 3").unwrap().starts_with(
+            "Round 3\n|..#....|\n|..#....|\n|####...|\n|..###..|\n|...#...|\n|..####.|\n"
+        ));
+        assert!(repl.execute("show").unwrap().starts_with("Round 3\n"));
+        repl.execute("reset").unwrap();
+        assert!(repl.execute("step").unwrap().starts_with("Round 1\n"));
+
+        let mut repl = load(1, "1000\n");
+        assert!(repl.execute("step").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let mut repl = Repl::default();
+
+        assert!(matches!(repl.execute("summary"), Err(ReplError::NoInput)));
+        assert!(matches!(
+            repl.execute("load 2022/26 input"),
+            Err(ReplError::UnknownPuzzle(_))
+        ));
+        assert!(matches!(
+            repl.execute("frobnicate"),
+            Err(ReplError::UnknownCommand(_))
+        ));
+    }
+}
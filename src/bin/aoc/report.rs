@@ -0,0 +1,333 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use advent_of_code::{days, AocError, Day, PuzzleId, SolveError};
+
+use crate::run::select;
+use crate::CliError;
+
+const DEFAULT_RUNS: usize = 5;
+const SLOWEST: usize = 5;
+const BAR_WIDTH: usize = 40;
+
+struct Options {
+    days: Vec<Day>,
+    runs: usize,
+    timings: bool,
+    output: Option<PathBuf>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, CliError> {
+    let mut options = Options {
+        days: vec![],
+        runs: DEFAULT_RUNS,
+        timings: false,
+        output: None,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => match args.next().map(|runs| runs.parse()) {
+                Some(Ok(runs)) if runs > 0 => options.runs = runs,
+                _ => return Err(CliError::Usage),
+            },
+            "--timings" => options.timings = true,
+            "--output" => options.output = Some(PathBuf::from(args.next().ok_or(CliError::Usage)?)),
+            _ => options.days.extend(select(arg)?),
+        }
+    }
+
+    if options.days.is_empty() {
+        options.days = days();
+    }
+
+    Ok(options)
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let options = parse_args(args)?;
+    let runs = options.timings.then_some(options.runs);
+    let rows: Vec<_> = options.days.iter().map(|day| measure(day, runs)).collect();
+    let report = render(&rows, runs);
+
+    match options.output {
+        Some(path) => fs::write(path, report)?,
+        None => print!("{}", report),
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Status {
+    Correct,
+    Wrong,
+    Unchecked,
+    Failed,
+    Unimplemented,
+    NoInput,
+}
+
+impl Status {
+    fn describe(&self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::Unchecked => "unchecked",
+            Status::Failed => "failed",
+            Status::Unimplemented => "not implemented",
+            Status::NoInput => "no input",
+        }
+    }
+}
+
+struct Row {
+    id: PuzzleId,
+    title: &'static str,
+    parts: [(Status, Option<Duration>); 2],
+}
+
+impl Row {
+    fn time(&self) -> Option<Duration> {
+        let times: Vec<_> = self.parts.iter().filter_map(|(_, time)| *time).collect();
+
+        if times.is_empty() {
+            None
+        } else {
+            Some(times.into_iter().sum())
+        }
+    }
+}
+
+// Without timings one run of each part tells its status.
+fn measure(day: &Day, runs: Option<usize>) -> Row {
+    let input = fs::read_to_string(day.id().input_path()).ok();
+    let part = |part| match &input {
+        Some(input) => {
+            let (status, time) = measure_part(day, part, input, runs.unwrap_or(1));
+            (status, time.filter(|_| runs.is_some()))
+        }
+        None => (Status::NoInput, None),
+    };
+
+    Row {
+        id: day.id(),
+        title: day.title(),
+        parts: [part(1), part(2)],
+    }
+}
+
+// Solves a part several times and keeps the median, which is steadier than
+// the mean when a run happens to be interrupted.
+fn measure_part(day: &Day, part: u8, input: &str, runs: usize) -> (Status, Option<Duration>) {
+    let mut times = vec![];
+    let mut answer = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = match part {
+            1 => day.solve_part_1(input.to_owned()),
+            _ => day.solve_part_2(input.to_owned()),
+        };
+        times.push(start.elapsed());
+
+        match result {
+            Ok(result) => answer = Some(result),
+            Err(AocError::Solving(SolveError::Unimplemented)) => {
+                return (Status::Unimplemented, None)
+            }
+            Err(_) => return (Status::Failed, None),
+        }
+    }
+
+    times.sort_unstable();
+
+    let status = match (fs::read_to_string(day.id().answer_path(part)), answer) {
+        (Ok(expected), Some(answer)) if expected.trim() == answer.trim() => Status::Correct,
+        (Ok(_), _) => Status::Wrong,
+        (Err(_), _) => Status::Unchecked,
+    };
+
+    (status, Some(times[times.len() / 2]))
+}
+
+// Without timings the report only changes when an answer or an input does.
+// Timings are rounded, but still differ between runs.
+fn render(rows: &[Row], runs: Option<usize>) -> String {
+    let mut years: Vec<_> = rows.iter().map(|row| row.id.year).collect();
+    years.sort_unstable();
+    years.dedup();
+
+    let mut output = String::from("# Advent of Code\n");
+
+    for year in years {
+        output.push_str(&format!(
+            "\n## {}\n\n| Day | Title | Part 1 | Part 2 |{}\n\
+             |----:|-------|--------|--------|{}\n",
+            year,
+            if runs.is_some() { " Median time |" } else { "" },
+            if runs.is_some() { "------------:|" } else { "" }
+        ));
+
+        let mut year_rows: Vec<_> = rows.iter().filter(|row| row.id.year == year).collect();
+        year_rows.sort_by_key(|row| row.id);
+
+        for row in year_rows {
+            output.push_str(&format!(
+                "| {} | {} | {} | {} |",
+                row.id.day,
+                row.title,
+                row.parts[0].0.describe(),
+                row.parts[1].0.describe()
+            ));
+
+            if runs.is_some() {
+                output.push_str(&format!(
+                    " {} |",
+                    row.time().map(format_duration).unwrap_or_default()
+                ));
+            }
+
+            output.push('\n');
+        }
+    }
+
+    if let Some(runs) = runs {
+        output.push_str(&format!(
+            "\nMedian of {} runs of each part.\n{}",
+            runs,
+            histogram(rows)
+        ));
+    }
+
+    output
+}
+
+fn histogram(rows: &[Row]) -> String {
+    let mut timed: Vec<_> = rows
+        .iter()
+        .filter_map(|row| row.time().map(|time| (row.id, time)))
+        .collect();
+
+    if timed.is_empty() {
+        return String::new();
+    }
+
+    timed.sort_by(|(a_id, a_time), (b_id, b_time)| b_time.cmp(a_time).then(a_id.cmp(b_id)));
+    timed.truncate(SLOWEST);
+
+    let slowest = timed[0].1.as_secs_f64().max(f64::MIN_POSITIVE);
+    let mut output = String::from("\n## Slowest days\n\n```\n");
+
+    for (id, time) in timed {
+        let width = (time.as_secs_f64() / slowest * BAR_WIDTH as f64).round() as usize;
+
+        output.push_str(&format!(
+            "{:<8} {:<width$} {}\n",
+            id.to_string(),
+            "#".repeat(width.max(1)),
+            format_duration(time),
+            width = BAR_WIDTH
+        ));
+    }
+
+    output.push_str("```\n");
+    output
+}
+
+// Rounds to two significant digits, so that timings only change in the report
+// when they change noticeably.
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    let (value, unit) = if seconds < 1e-3 {
+        (seconds * 1e6, "µs")
+    } else if seconds < 1.0 {
+        (seconds * 1e3, "ms")
+    } else {
+        (seconds, "s")
+    };
+
+    if value >= 100.0 {
+        format!("{:.0} {}", (value / 10.0).round() * 10.0, unit)
+    } else if value >= 10.0 {
+        format!("{:.0} {}", value, unit)
+    } else {
+        format!("{:.1} {}", value, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, parts: [(Status, Option<u64>); 2]) -> Row {
+        Row {
+            id: PuzzleId::new(2022, day),
+            title: "Title",
+            parts: parts.map(|(status, millis)| (status, millis.map(Duration::from_millis))),
+        }
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_micros(213)), "210 µs");
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12 ms");
+        assert_eq!(format_duration(Duration::from_micros(1_250)), "1.2 ms");
+        assert_eq!(format_duration(Duration::from_millis(26_400)), "26 s");
+    }
+
+    #[test]
+    fn report() {
+        let rows = vec![
+            row(2, [(Status::Correct, Some(4)), (Status::Wrong, Some(4))]),
+            row(
+                1,
+                [(Status::Unchecked, Some(2)), (Status::Unimplemented, None)],
+            ),
+            row(3, [(Status::NoInput, None), (Status::NoInput, None)]),
+        ];
+
+        assert_eq!(
+            render(&rows, Some(3)),
+            "# Advent of Code
+
+## 2022
+
+| Day | Title | Part 1 | Part 2 | Median time |
+|----:|-------|--------|--------|------------:|
+| 1 | Title | unchecked | not implemented | 2.0 ms |
+| 2 | Title | correct | wrong | 8.0 ms |
+| 3 | Title | no input | no input |  |
+
+Median of 3 runs of each part.
+
+## Slowest days
+
+```
+2022/2   ######################################## 8.0 ms
+2022/1   ##########                               2.0 ms
+```
+"
+        );
+    }
+
+    #[test]
+    fn without_timings() {
+        let rows = vec![row(1, [(Status::Correct, None), (Status::Wrong, None)])];
+
+        assert_eq!(
+            render(&rows, None),
+            "# Advent of Code
+
+## 2022
+
+| Day | Title | Part 1 | Part 2 |
+|----:|-------|--------|--------|
+| 1 | Title | correct | wrong |
+"
+        );
+    }
+}
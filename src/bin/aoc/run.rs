@@ -0,0 +1,334 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use advent_of_code::{days, get_puzzle, AocError, Cache, Day, PuzzleId};
+
+use crate::{CliError, ExitCode};
+
+struct Options {
+    days: Vec<Day>,
+    parts: Vec<u8>,
+    input: Option<PathBuf>,
+    timeout: Option<Duration>,
+    use_cache: bool,
+    clear_cache: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, CliError> {
+    let mut options = Options {
+        days: vec![],
+        parts: vec![1, 2],
+        input: None,
+        timeout: None,
+        use_cache: true,
+        clear_cache: false,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => match args.next().map(|part| part.as_str()) {
+                Some("1") => options.parts = vec![1],
+                Some("2") => options.parts = vec![2],
+                _ => return Err(CliError::Usage),
+            },
+            "--input" => {
+                options.input = Some(PathBuf::from(args.next().ok_or(CliError::Usage)?));
+            }
+            "--timeout" => {
+                let seconds = args.next().and_then(|seconds| seconds.parse().ok());
+                let timeout = seconds.and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+                options.timeout = Some(timeout.ok_or(CliError::Usage)?);
+            }
+            "--no-cache" => options.use_cache = false,
+            "--clear-cache" => options.clear_cache = true,
+            _ => options.days.extend(select(arg)?),
+        }
+    }
+
+    if (options.days.is_empty() && !options.clear_cache)
+        || (options.input.is_some() && options.days.len() > 1)
+    {
+        Err(CliError::Usage)
+    } else {
+        Ok(options)
+    }
+}
+
+pub fn select(arg: &str) -> Result<Vec<Day>, CliError> {
+    match arg.parse::<u16>() {
+        Ok(year) if year > 25 => {
+            let year_days: Vec<_> = days().into_iter().filter(|d| d.year() == year).collect();
+
+            if year_days.is_empty() {
+                Err(CliError::UnknownPuzzle(PuzzleId::new(year, 1)))
+            } else {
+                Ok(year_days)
+            }
+        }
+        _ => {
+            let id = arg.parse::<PuzzleId>().map_err(AocError::from)?;
+            let day = get_puzzle(id).ok_or(CliError::UnknownPuzzle(id))?;
+
+            Ok(vec![day])
+        }
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let options = parse_args(args)?;
+    let cache = Cache::open_default();
+    let mut summary = Summary::default();
+
+    if options.clear_cache {
+        cache.clear()?;
+    }
+
+    for day in options.days.iter() {
+        let path = options
+            .input
+            .clone()
+            .unwrap_or_else(|| day.id().input_path());
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {}: {}: {}", day.id(), path.display(), e);
+
+                for _ in options.parts.iter() {
+                    summary.add(Outcome::Failed(ExitCode::Input));
+                }
+
+                continue;
+            }
+        };
+
+        for &part in options.parts.iter() {
+            let cached = if options.use_cache {
+                cache.get(day, part, &input)
+            } else {
+                None
+            };
+
+            let result = match cached {
+                Some(answer) => Some(Ok((answer, " (cached)"))),
+                None => solve(*day, part, &input, options.timeout).map(|result| {
+                    result.map(|answer| {
+                        if let Err(e) = cache.put(day, part, &input, &answer) {
+                            eprintln!("Failed to cache {} part {}: {}", day.id(), part, e);
+                        }
+
+                        (answer, "")
+                    })
+                }),
+            };
+
+            let outcome = match result {
+                Some(Ok((answer, marker))) => {
+                    let (outcome, check) = check_answer(day.id(), part, &answer);
+                    println!("{} part {}: {}{}{}", day.id(), part, answer, marker, check);
+                    outcome
+                }
+                Some(Err(e)) => {
+                    eprintln!("{} part {}: {}", day.id(), part, e);
+                    Outcome::Failed(ExitCode::from(&e))
+                }
+                None => {
+                    eprintln!("{} part {}: Timed out", day.id(), part);
+                    Outcome::TimedOut
+                }
+            };
+
+            summary.add(outcome);
+        }
+    }
+
+    if summary.len() > 1 {
+        println!("{}", summary);
+    }
+
+    match summary.exit_code() {
+        None => Ok(()),
+        Some(_) => Err(CliError::Failed(summary)),
+    }
+}
+
+// Solves on another thread when there is a time limit, and gives up on it
+// once the limit has passed. The process exits without waiting for it.
+fn solve(
+    day: Day,
+    part: u8,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Option<Result<String, AocError>> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return Some(solve_part(&day, part, input.to_owned())),
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+
+    thread::spawn(move || {
+        let _ = sender.send(solve_part(&day, part, input));
+    });
+
+    receiver.recv_timeout(timeout).ok()
+}
+
+fn solve_part(day: &Day, part: u8, input: String) -> Result<String, AocError> {
+    match part {
+        1 => day.solve_part_1(input),
+        _ => day.solve_part_2(input),
+    }
+}
+
+fn check_answer(id: PuzzleId, part: u8, answer: &str) -> (Outcome, String) {
+    match fs::read_to_string(id.answer_path(part)) {
+        Ok(expected) if expected.trim() == answer.trim() => {
+            (Outcome::Correct, String::from(" (correct)"))
+        }
+        Ok(expected) => (Outcome::Wrong, format!(" (expected {})", expected.trim())),
+        Err(_) => (Outcome::Answered, String::new()),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Correct,
+    Answered,
+    Wrong,
+    Failed(ExitCode),
+    TimedOut,
+}
+
+impl Outcome {
+    fn exit_code(&self) -> Option<ExitCode> {
+        match self {
+            Outcome::Correct | Outcome::Answered => None,
+            Outcome::Wrong => Some(ExitCode::Mismatch),
+            Outcome::Failed(code) => Some(*code),
+            Outcome::TimedOut => Some(ExitCode::Timeout),
+        }
+    }
+}
+
+/// The outcomes of every part that `aoc run` attempted.
+#[derive(Debug, Default)]
+pub struct Summary(Vec<Outcome>);
+
+impl Summary {
+    fn add(&mut self, outcome: Outcome) {
+        self.0.push(outcome);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn count(&self, matches: impl Fn(&Outcome) -> bool) -> usize {
+        self.0.iter().filter(|outcome| matches(outcome)).count()
+    }
+
+    pub fn failures(&self) -> usize {
+        self.count(|outcome| outcome.exit_code().is_some())
+    }
+
+    /// The exit code of the first failure, if any part failed.
+    pub fn exit_code(&self) -> Option<ExitCode> {
+        self.0.iter().find_map(Outcome::exit_code)
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let counts = [
+            ("correct", self.count(|o| *o == Outcome::Correct)),
+            ("unchecked", self.count(|o| *o == Outcome::Answered)),
+            ("wrong", self.count(|o| *o == Outcome::Wrong)),
+            ("failed", self.count(|o| matches!(o, Outcome::Failed(_)))),
+            ("timed out", self.count(|o| *o == Outcome::TimedOut)),
+        ];
+
+        let counts: Vec<_> = counts
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(name, count)| format!("{} {}", count, name))
+            .collect();
+
+        write!(f, "{} parts: {}", self.len(), counts.join(", "))
+    }
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn selection() {
+        let options = parse_args(&args(&["2022/17", "3", "--part", "2"])).unwrap();
+        let ids: Vec<_> = options.days.iter().map(|day| day.id()).collect();
+
+        assert_eq!(ids, vec![PuzzleId::new(2022, 17), PuzzleId::new(2022, 3)]);
+        assert_eq!(options.parts, vec![2]);
+
+        assert_eq!(parse_args(&args(&["2022"])).unwrap().days.len(), 25);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            parse_args(&args(&["2015/1"])),
+            Err(CliError::UnknownPuzzle(_))
+        ));
+        assert!(matches!(parse_args(&args(&[])), Err(CliError::Usage)));
+        assert!(parse_args(&args(&["--clear-cache"])).is_ok());
+        assert!(matches!(
+            parse_args(&args(&["2022", "--input", "file"])),
+            Err(CliError::Usage)
+        ));
+        assert!(matches!(
+            parse_args(&args(&["1", "--part", "3"])),
+            Err(CliError::Usage)
+        ));
+        assert!(matches!(
+            parse_args(&args(&["1", "--timeout", "-1"])),
+            Err(CliError::Usage)
+        ));
+    }
+
+    #[test]
+    fn timeout() {
+        let options = parse_args(&args(&["2022/1", "--timeout", "0.5"])).unwrap();
+        assert_eq!(options.timeout, Some(Duration::from_millis(500)));
+
+        let day = get_puzzle(PuzzleId::new(2022, 1)).unwrap();
+        assert!(solve(day, 1, "1\n", Some(Duration::from_secs(10))).is_some());
+    }
+
+    #[test]
+    fn summary() {
+        let mut summary = Summary::default();
+        summary.add(Outcome::Correct);
+        summary.add(Outcome::Correct);
+        summary.add(Outcome::Failed(ExitCode::Parse));
+        summary.add(Outcome::TimedOut);
+
+        assert_eq!(
+            summary.to_string(),
+            "4 parts: 2 correct, 1 failed, 1 timed out"
+        );
+        assert_eq!(summary.failures(), 2);
+        assert_eq!(summary.exit_code(), Some(ExitCode::Parse));
+        assert_eq!(Summary::default().exit_code(), None);
+    }
+}
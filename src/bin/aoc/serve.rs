@@ -0,0 +1,653 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use advent_of_code::{days, get_puzzle, Day, PuzzleId};
+
+use crate::CliError;
+
+const DEFAULT_PORT: u16 = 8022;
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+const STYLE: &str = "
+body { font-family: sans-serif; background: #0f0f23; color: #ccc; margin: 2em; }
+a { color: #009900; }
+h1, h2 { color: #00cc00; }
+.calendar { display: grid; grid-template-columns: repeat(5, 12em); gap: 0.5em; }
+.day { border: 1px solid #333; padding: 0.5em; background: #10101a; }
+.day form, .day a { display: inline; margin-right: 0.5em; }
+.correct { color: #ffff66; }
+.wrong, .failed { color: #ff6666; }
+.answered { color: #99ccff; }
+.missing { color: #666; }
+pre { background: #10101a; border: 1px solid #333; padding: 1em; line-height: 1; }
+textarea { width: 40em; height: 10em; }
+";
+
+// Fills the text area from a chosen file, so uploads are plain form posts.
+const SCRIPT: &str = "
+document.getElementById('file').addEventListener('change', function (event) {
+    var reader = new FileReader();
+    reader.onload = function () { document.getElementById('input').value = reader.result; };
+    reader.readAsText(event.target.files[0]);
+});
+";
+
+fn parse_args(args: &[String]) -> Result<u16, CliError> {
+    match args {
+        [] => Ok(DEFAULT_PORT),
+        [flag, port] if flag == "--port" => port.parse().map_err(|_| CliError::Usage),
+        _ => Err(CliError::Usage),
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let port = parse_args(args)?;
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    let mut dashboard = Dashboard::default();
+
+    println!("Serving the dashboard on http://127.0.0.1:{}/", port);
+
+    for stream in listener.incoming() {
+        if let Err(e) = stream.and_then(|stream| dashboard.serve(stream)) {
+            eprintln!("Failed to serve a request: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    form: HashMap<String, String>,
+}
+
+impl Request {
+    fn read(reader: &mut impl BufRead) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Malformed request");
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        let mut parts = line.split_whitespace();
+        let method = parts.next().ok_or_else(invalid)?.to_owned();
+        let target = parts.next().ok_or_else(invalid)?;
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let (path, query) = (path.to_owned(), parse_form(query));
+
+        let mut length = 0;
+
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+
+            match line.trim_end().split_once(':') {
+                Some((name, value)) if name.eq_ignore_ascii_case("content-length") => {
+                    length = value.trim().parse().map_err(|_| invalid())?;
+                }
+                Some(_) => (),
+                None => break,
+            }
+        }
+
+        if length > MAX_BODY {
+            return Err(invalid());
+        }
+
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+
+        Ok(Self {
+            method,
+            path,
+            query,
+            form: parse_form(&String::from_utf8_lossy(&body)),
+        })
+    }
+}
+
+fn parse_form(form: &str) -> HashMap<String, String> {
+    form.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(name), decode(value))
+        })
+        .collect()
+}
+
+// Decodes application/x-www-form-urlencoded text.
+fn decode(text: &str) -> String {
+    let mut bytes = vec![];
+    let mut chars = text.bytes();
+
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = chars.by_ref().take(2).collect();
+                let value = std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                match value {
+                    Some(value) => bytes.push(value),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            _ => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+struct Response {
+    status: &'static str,
+    location: Option<String>,
+    body: String,
+}
+
+impl Response {
+    fn page(title: &str, content: &str) -> Self {
+        Self {
+            status: "200 OK",
+            location: None,
+            body: format!(
+                "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{}</title>\
+                 <style>{}</style></head><body>{}</body></html>\n",
+                escape(title),
+                STYLE,
+                content
+            ),
+        }
+    }
+
+    fn redirect(location: String) -> Self {
+        Self {
+            status: "303 See Other",
+            location: Some(location),
+            body: String::new(),
+        }
+    }
+
+    fn error(status: &'static str, message: &str) -> Self {
+        Self {
+            status,
+            location: None,
+            body: format!("{}\n", message),
+        }
+    }
+
+    fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        let content_type = if self.body.starts_with("<!DOCTYPE") {
+            "text/html; charset=utf-8"
+        } else {
+            "text/plain; charset=utf-8"
+        };
+
+        write!(writer, "HTTP/1.1 {}\r\n", self.status)?;
+        write!(writer, "Content-Type: {}\r\n", content_type)?;
+        write!(writer, "Content-Length: {}\r\n", self.body.len())?;
+
+        if let Some(location) = &self.location {
+            write!(writer, "Location: {}\r\n", location)?;
+        }
+
+        write!(writer, "Connection: close\r\n\r\n{}", self.body)?;
+        writer.flush()
+    }
+}
+
+enum Outcome {
+    Correct(String),
+    Wrong(String, String),
+    Answered(String),
+    Failed(String),
+    Unimplemented,
+}
+
+impl Outcome {
+    fn new(id: PuzzleId, part: u8, result: Result<String, String>) -> Self {
+        let answer = match result {
+            Ok(answer) => answer,
+            Err(e) => return Outcome::Failed(e),
+        };
+
+        match fs::read_to_string(id.answer_path(part)) {
+            Ok(expected) if expected.trim() == answer.trim() => Outcome::Correct(answer),
+            Ok(expected) => Outcome::Wrong(answer, expected.trim().to_owned()),
+            Err(_) => Outcome::Answered(answer),
+        }
+    }
+
+    fn render(&self, part: u8) -> String {
+        match self {
+            Outcome::Correct(answer) => format!(
+                "<div class=\"correct\">{} &#9733; {}</div>",
+                part,
+                escape(answer)
+            ),
+            Outcome::Wrong(answer, expected) => format!(
+                "<div class=\"wrong\">{} &#10007; {} (expected {})</div>",
+                part,
+                escape(answer),
+                escape(expected)
+            ),
+            Outcome::Answered(answer) => {
+                format!("<div class=\"answered\">{} {}</div>", part, escape(answer))
+            }
+            Outcome::Failed(e) => format!("<div class=\"failed\">{} {}</div>", part, escape(e)),
+            Outcome::Unimplemented => {
+                format!("<div class=\"missing\">{} not implemented</div>", part)
+            }
+        }
+    }
+}
+
+struct Timing {
+    id: PuzzleId,
+    part: u8,
+    elapsed: Duration,
+}
+
+#[derive(Default)]
+struct Dashboard {
+    uploads: HashMap<PuzzleId, String>,
+    outcomes: HashMap<(PuzzleId, u8), Outcome>,
+    // Only the latest timing of each part, in the order they were first run.
+    timings: Vec<Timing>,
+}
+
+impl Dashboard {
+    fn serve(&mut self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let response = match Request::read(&mut reader) {
+            Ok(request) => self.handle(&request),
+            Err(e) => Response::error("400 Bad Request", &e.to_string()),
+        };
+
+        response.write(&mut &stream)
+    }
+
+    fn handle(&mut self, request: &Request) -> Response {
+        let segments: Vec<_> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("GET", []) => Response::page("Advent of Code", &self.calendar()),
+            ("POST", ["run"]) => {
+                for day in days() {
+                    self.run(&day);
+                }
+
+                Response::redirect(String::from("/"))
+            }
+            ("POST", ["run", year, day]) => match puzzle(year, day) {
+                Some(day) => {
+                    self.run(&day);
+                    Response::redirect(format!("/#{}", day.id()))
+                }
+                None => Response::error("404 Not Found", "Unknown puzzle"),
+            },
+            ("POST", ["input"]) => self.upload(&request.form),
+            ("GET", ["visualise", year, day]) => match puzzle(year, day) {
+                Some(day) => self.visualise(&day, &request.query),
+                None => Response::error("404 Not Found", "Unknown puzzle"),
+            },
+            ("GET", _) => Response::error("404 Not Found", "Not found"),
+            _ => Response::error("405 Method Not Allowed", "Method not allowed"),
+        }
+    }
+
+    fn input(&self, id: PuzzleId) -> Result<String, String> {
+        match self.uploads.get(&id) {
+            Some(input) => Ok(input.clone()),
+            None => fs::read_to_string(id.input_path()).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn run(&mut self, day: &Day) {
+        let (parts, unimplemented): (Vec<u8>, Vec<u8>) =
+            (1..=2).partition(|&part| day.implements(part));
+
+        for part in unimplemented {
+            self.outcomes
+                .insert((day.id(), part), Outcome::Unimplemented);
+        }
+
+        let input = match self.input(day.id()) {
+            Ok(input) => input,
+            Err(e) => {
+                for part in parts {
+                    self.outcomes
+                        .insert((day.id(), part), Outcome::Failed(e.clone()));
+                }
+
+                return;
+            }
+        };
+
+        for part in parts {
+            let start = Instant::now();
+            let result = match part {
+                1 => day.solve_part_1(input.clone()),
+                _ => day.solve_part_2(input.clone()),
+            };
+            let elapsed = start.elapsed();
+
+            let timing = Timing {
+                id: day.id(),
+                part,
+                elapsed,
+            };
+
+            match self
+                .timings
+                .iter_mut()
+                .find(|timing| timing.id == day.id() && timing.part == part)
+            {
+                Some(previous) => *previous = timing,
+                None => self.timings.push(timing),
+            }
+            self.outcomes.insert(
+                (day.id(), part),
+                Outcome::new(day.id(), part, result.map_err(|e| e.to_string())),
+            );
+        }
+    }
+
+    fn upload(&mut self, form: &HashMap<String, String>) -> Response {
+        let id = form
+            .get("puzzle")
+            .and_then(|puzzle| puzzle.parse::<PuzzleId>().ok());
+
+        match (id, form.get("input")) {
+            (Some(id), Some(input)) if get_puzzle(id).is_some() => {
+                self.uploads.insert(id, input.replace("\r\n", "\n"));
+                self.outcomes.retain(|(outcome_id, _), _| *outcome_id != id);
+
+                Response::redirect(format!("/#{}", id))
+            }
+            _ => Response::error("400 Bad Request", "Expected a known puzzle and an input"),
+        }
+    }
+
+    fn calendar(&self) -> String {
+        let mut html = String::from("<h1>Advent of Code</h1>");
+        html += "<form method=\"post\" action=\"/run\"><button>Run every day</button></form>";
+
+        let all_days = days();
+        let mut years: Vec<_> = all_days.iter().map(|day| day.year()).collect();
+        years.dedup();
+
+        for year in years {
+            let _ = write!(html, "<h2>{}</h2><div class=\"calendar\">", year);
+
+            for day in all_days.iter().filter(|day| day.year() == year) {
+                html += &self.cell(day);
+            }
+
+            html += "</div>";
+        }
+
+        html += "<h2>Timings</h2>";
+        html += &self.chart();
+        html += &upload_form(&all_days);
+
+        html
+    }
+
+    fn cell(&self, day: &Day) -> String {
+        let id = day.id();
+        let source = if self.uploads.contains_key(&id) {
+            "uploaded input"
+        } else if id.input_path().exists() {
+            "input file"
+        } else {
+            "no input"
+        };
+
+        let mut html = format!(
+            "<div class=\"day\" id=\"{}\"><b>Day {}</b> <span class=\"missing\">{}</span>",
+            id, id.day, source
+        );
+
+        for part in 1..=2 {
+            match self.outcomes.get(&(id, part)) {
+                Some(outcome) => html += &outcome.render(part),
+                None => {
+                    let _ = write!(html, "<div class=\"missing\">{} not run</div>", part);
+                }
+            }
+        }
+
+        let _ = write!(
+            html,
+            "<form method=\"post\" action=\"/run/{}/{}\"><button>Run</button></form>",
+            id.year, id.day
+        );
+
+        if day.is_simulated() {
+            let _ = write!(
+                html,
+                "<a href=\"/visualise/{}/{}\">Visualise</a>",
+                id.year, id.day
+            );
+        }
+
+        html + "</div>"
+    }
+
+    // A bar per run, scaled to the slowest one.
+    fn chart(&self) -> String {
+        const BAR: usize = 14;
+        const WIDTH: f64 = 600.0;
+
+        if self.timings.is_empty() {
+            return String::from("<p class=\"missing\">Nothing has been run yet.</p>");
+        }
+
+        let slowest = self
+            .timings
+            .iter()
+            .map(|timing| timing.elapsed.as_secs_f64())
+            .fold(f64::EPSILON, f64::max);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"900\" height=\"{}\">",
+            self.timings.len() * BAR
+        );
+
+        for (i, timing) in self.timings.iter().enumerate() {
+            let y = i * BAR;
+            let seconds = timing.elapsed.as_secs_f64();
+            let width = (seconds / slowest * WIDTH).max(1.0);
+
+            let _ = write!(
+                svg,
+                "<text x=\"0\" y=\"{}\" fill=\"#ccc\" font-size=\"11\">{} part {}</text>\
+                 <rect x=\"110\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"#009900\"/>\
+                 <text x=\"{:.1}\" y=\"{}\" fill=\"#ccc\" font-size=\"11\">{:.3} ms</text>",
+                y + 11,
+                timing.id,
+                timing.part,
+                y + 2,
+                width,
+                BAR - 4,
+                width + 115.0,
+                y + 11,
+                seconds * 1000.0
+            );
+        }
+
+        svg + "</svg>"
+    }
+
+    fn visualise(&self, day: &Day, query: &HashMap<String, String>) -> Response {
+        let rounds: usize = query
+            .get("round")
+            .and_then(|round| round.parse().ok())
+            .unwrap_or(0);
+
+        let simulator = self
+            .input(day.id())
+            .map_err(|e| e.to_string())
+            .and_then(|input| match day.simulate(input) {
+                Some(simulator) => simulator.map_err(|e| e.to_string()),
+                None => Err(String::from("This day has no simulation")),
+            });
+
+        let mut simulator = match simulator {
+            Ok(simulator) => simulator,
+            Err(e) => return Response::error("400 Bad Request", &e),
+        };
+
+        while simulator.round() < rounds && simulator.step() {}
+
+        let id = day.id();
+        let round = simulator.round();
+        let mut html = format!("<h1>{}</h1><p><a href=\"/\">Calendar</a> ", id);
+
+        for step in [1, 10, 100, 1000].iter() {
+            let _ = write!(
+                html,
+                "<a href=\"/visualise/{}/{}?round={}\">+{}</a> ",
+                id.year,
+                id.day,
+                round + step,
+                step
+            );
+        }
+
+        let _ = write!(
+            html,
+            "</p><p>Round {}{}</p><pre>{}</pre>",
+            round,
+            if simulator.is_finished() {
+                " (finished)"
+            } else {
+                ""
+            },
+            escape(&simulator.render())
+        );
+
+        Response::page(&format!("{} round {}", id, round), &html)
+    }
+}
+
+fn puzzle(year: &str, day: &str) -> Option<Day> {
+    get_puzzle(PuzzleId::new(year.parse().ok()?, day.parse().ok()?))
+}
+
+fn upload_form(days: &[Day]) -> String {
+    let options: String = days
+        .iter()
+        .map(|day| format!("<option>{}</option>", day.id()))
+        .collect();
+
+    format!(
+        "<h2>Upload an input</h2>\
+         <form method=\"post\" action=\"/input\">\
+         <p><select name=\"puzzle\">{}</select> <input type=\"file\" id=\"file\"></p>\
+         <p><textarea name=\"input\" id=\"input\"></textarea></p>\
+         <p><button>Upload</button></p></form><script>{}</script>",
+        options, SCRIPT
+    )
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        let text = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        );
+
+        Request::read(&mut text.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn requests() {
+        let request = request("POST", "/visualise/2022/17?round=3", "a=1+2%2B3&b");
+
+        assert_eq!(request.path, "/visualise/2022/17");
+        assert_eq!(request.query["round"], "3");
+        assert_eq!(request.form["a"], "1 2+3");
+        assert_eq!(request.form["b"], "");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+
+    #[test]
+    fn dashboard() {
+        let mut dashboard = Dashboard::default();
+        let input = "puzzle=2022%2F1&input=1000%0D%0A2000%0D%0A%0D%0A4000%0D%0A";
+
+        let response = dashboard.handle(&request("POST", "/input", input));
+        assert_eq!(response.location.as_deref(), Some("/#2022/1"));
+
+        let response = dashboard.handle(&request("POST", "/run/2022/1", ""));
+        assert_eq!(response.status, "303 See Other");
+
+        let page = dashboard.handle(&request("GET", "/", "")).body;
+        assert!(page.contains("4000"));
+        assert!(page.contains("2022/1 part 2"));
+
+        dashboard.handle(&request("POST", "/run/2022/1", ""));
+        assert_eq!(dashboard.timings.len(), 2);
+
+        dashboard.handle(&request("POST", "/input", "puzzle=2022%2F25&input=1%3D%0A"));
+        dashboard.handle(&request("POST", "/run/2022/25", ""));
+        assert!(matches!(
+            dashboard.outcomes[&(PuzzleId::new(2022, 25), 2)],
+            Outcome::Unimplemented
+        ));
+        assert_eq!(dashboard.timings.len(), 3);
+
+        let response = dashboard.handle(&request("POST", "/input", "puzzle=2015%2F1&input=1"));
+        assert_eq!(response.status, "400 Bad Request");
+        assert_eq!(
+            dashboard.handle(&request("GET", "/missing", "")).status,
+            "404 Not Found"
+        );
+    }
+
+    #[test]
+    fn visualisation() {
+        let mut dashboard = Dashboard::default();
+        dashboard.handle(&request(
+            "POST",
+            "/input",
+            "puzzle=2022%2F17&input=%3E%3E%3C",
+        ));
+
+        let page = dashboard
+            .handle(&request("GET", "/visualise/2022/17?round=2", ""))
+            .body;
+        assert!(page.contains("Round 2"));
+        assert!(page.contains("+-------+"));
+
+        let response = dashboard.handle(&request("GET", "/visualise/2022/1", ""));
+        assert_eq!(response.status, "400 Bad Request");
+    }
+}
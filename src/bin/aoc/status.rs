@@ -0,0 +1,130 @@
+use advent_of_code::{days, latest_year, Day, PuzzleId};
+
+use crate::CliError;
+
+const DAYS: u8 = 25;
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let year = match args {
+        [] => latest_year(),
+        [year] => year.parse().map_err(|_| CliError::Usage)?,
+        _ => return Err(CliError::Usage),
+    };
+
+    let days: Vec<_> = days()
+        .into_iter()
+        .filter(|day| day.year() == year)
+        .collect();
+    let entries: Vec<_> = days.iter().map(entry).collect();
+    print!("{}", render(year, &entries));
+
+    Ok(())
+}
+
+struct Entry {
+    id: PuzzleId,
+    title: String,
+    tags: String,
+    parts: [bool; 2],
+}
+
+fn entry(day: &Day) -> Entry {
+    Entry {
+        id: day.id(),
+        title: day.title().to_owned(),
+        tags: day
+            .tags()
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        parts: [day.implements(1), day.implements(2)],
+    }
+}
+
+fn render(year: u16, entries: &[Entry]) -> String {
+    let mut output = format!("{:<4} 1 2  {:<26} Tags\n", year, "Title");
+    let mut implemented = 0;
+
+    for day in 1..=DAYS {
+        match entries
+            .iter()
+            .find(|entry| entry.id == PuzzleId::new(year, day))
+        {
+            Some(entry) => {
+                implemented += entry.parts.iter().filter(|part| **part).count();
+
+                output.push_str(&format!(
+                    "{:>4} {} {}  {:<26} {}\n",
+                    day,
+                    mark(entry.parts[0]),
+                    mark(entry.parts[1]),
+                    entry.title,
+                    entry.tags
+                ));
+            }
+            None => output.push_str(&format!("{:>4} . .\n", day)),
+        }
+    }
+
+    output.push_str(&format!(
+        "\n{} of {} parts implemented (* yes, - no, . no solver)\n",
+        implemented,
+        2 * DAYS as usize
+    ));
+
+    output
+}
+
+fn mark(part: bool) -> char {
+    if part {
+        '*'
+    } else {
+        '-'
+    }
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix() {
+        let entries = vec![
+            Entry {
+                id: PuzzleId::new(2022, 1),
+                title: String::from("Calorie Counting"),
+                tags: String::from("parsing"),
+                parts: [true, true],
+            },
+            Entry {
+                id: PuzzleId::new(2022, 3),
+                title: String::from("Plugin"),
+                tags: String::new(),
+                parts: [true, false],
+            },
+        ];
+        let output = render(2022, &entries);
+        let lines: Vec<_> = output.lines().collect();
+
+        assert_eq!(lines.len(), 28);
+        assert_eq!(lines[0], "2022 1 2  Title                      Tags");
+        assert_eq!(lines[1], "   1 * *  Calorie Counting           parsing");
+        assert_eq!(lines[2], "   2 . .");
+        assert_eq!(lines[3].trim_end(), "   3 * -  Plugin");
+        assert!(lines[27].starts_with("3 of 50 parts implemented"));
+    }
+
+    #[test]
+    fn entries() {
+        let days: Vec<_> = days()
+            .into_iter()
+            .filter(|day| [1, 16].contains(&day.day()))
+            .collect();
+        let entries: Vec<_> = days.iter().map(entry).collect();
+
+        assert_eq!(entries[0].parts, [true, true]);
+        assert_eq!(entries[1].parts, [true, false]);
+        assert_eq!(entries[1].tags, "graph, search");
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::PathBuf;
+
+use advent_of_code::Day;
+
+use crate::run::select;
+use crate::CliError;
+
+struct Options {
+    days: Vec<Day>,
+    input: Option<PathBuf>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, CliError> {
+    let mut options = Options {
+        days: vec![],
+        input: None,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                options.input = Some(PathBuf::from(args.next().ok_or(CliError::Usage)?));
+            }
+            _ => options.days.extend(select(arg)?),
+        }
+    }
+
+    if options.days.is_empty() || (options.input.is_some() && options.days.len() > 1) {
+        Err(CliError::Usage)
+    } else {
+        Ok(options)
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let options = parse_args(args)?;
+    let mut invalid = 0;
+
+    for day in options.days.iter() {
+        let path = options
+            .input
+            .clone()
+            .unwrap_or_else(|| day.id().input_path());
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{}: {}: {}", day.id(), path.display(), e);
+                invalid += 1;
+                continue;
+            }
+        };
+
+        if !validate(day, input) {
+            invalid += 1;
+        }
+    }
+
+    if invalid == 0 {
+        Ok(())
+    } else {
+        Err(CliError::Invalid(invalid))
+    }
+}
+
+fn validate(day: &Day, input: String) -> bool {
+    let result = day
+        .summarise(input.clone())
+        .and_then(|summary| Ok((summary, day.validate(input)?)));
+
+    match result {
+        Ok((summary, problems)) => {
+            println!("{}: {}", day.id(), summary);
+
+            for problem in problems.iter() {
+                println!("  {}", problem);
+            }
+
+            problems.is_empty()
+        }
+        Err(e) => {
+            println!("{}: {}", day.id(), e);
+            false
+        }
+    }
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use advent_of_code::get_day;
+
+    use super::*;
+
+    #[test]
+    fn validation() {
+        let day = get_day(5).unwrap();
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+
+        assert!(validate(&day, String::from(input)));
+        assert!(!validate(&day, input.replace("to 1", "to 4")));
+        assert!(!validate(&day, String::from("move 1 from 2 to 1\n")));
+    }
+
+    #[test]
+    fn missing_input() {
+        let args: Vec<_> = ["2022/5", "--input", "no such file"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+
+        assert!(matches!(run(&args), Err(CliError::Invalid(1))));
+    }
+}
@@ -0,0 +1,293 @@
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use advent_of_code::{get_puzzle, AocError, Day, PuzzleId};
+
+use crate::CliError;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+// Clears the terminal and moves the cursor to the top left.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+struct Options {
+    day: Day,
+    paths: Vec<PathBuf>,
+    interval: Duration,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, CliError> {
+    let mut id = None;
+    let mut paths = vec![];
+    let mut interval = DEFAULT_INTERVAL;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => paths.push(PathBuf::from(args.next().ok_or(CliError::Usage)?)),
+            "--interval" => match args.next().map(|millis| millis.parse()) {
+                Some(Ok(millis)) => interval = Duration::from_millis(millis),
+                _ => return Err(CliError::Usage),
+            },
+            _ if id.is_none() => id = Some(arg.parse::<PuzzleId>().map_err(AocError::from)?),
+            _ => return Err(CliError::Usage),
+        }
+    }
+
+    let id = id.ok_or(CliError::Usage)?;
+    let day = get_puzzle(id).ok_or(CliError::UnknownPuzzle(id))?;
+
+    if paths.is_empty() {
+        paths.push(id.input_path());
+        paths.extend(day.example_path());
+    }
+
+    Ok(Options {
+        day,
+        paths,
+        interval,
+    })
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let options = parse_args(args)?;
+    let mut stamps = vec![];
+    let mut previous = vec![];
+
+    loop {
+        let current: Vec<_> = options.paths.iter().map(|path| stamp(path)).collect();
+
+        if current != stamps {
+            let evaluations: Vec<_> = options
+                .paths
+                .iter()
+                .map(|path| evaluate(&options.day, path))
+                .collect();
+
+            print!(
+                "{}{}",
+                CLEAR,
+                render(options.day.id(), &evaluations, &previous)
+            );
+            io::stdout().flush()?;
+
+            stamps = current;
+            previous = evaluations;
+        }
+
+        thread::sleep(options.interval);
+    }
+}
+
+// Polling the modification time and size works on every platform and file
+// system, without a notification service.
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+struct Evaluation {
+    path: PathBuf,
+    summary: Result<String, String>,
+    parts: Vec<(Result<String, String>, Duration)>,
+}
+
+fn evaluate(day: &Day, path: &Path) -> Evaluation {
+    let input = match fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(e) => {
+            return Evaluation {
+                path: path.to_owned(),
+                summary: Err(e.to_string()),
+                parts: vec![],
+            }
+        }
+    };
+
+    let parts = [1, 2]
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = catch(|| match part {
+                1 => day.solve_part_1(input.clone()),
+                _ => day.solve_part_2(input.clone()),
+            });
+
+            (answer, start.elapsed())
+        })
+        .collect();
+
+    Evaluation {
+        path: path.to_owned(),
+        summary: catch(|| day.summarise(input)),
+        parts,
+    }
+}
+
+// A panicking solver is shown as an error, so that watching goes on.
+fn catch<F>(f: F) -> Result<String, String>
+where
+    F: FnOnce() -> Result<String, AocError>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(answer) => answer.map_err(|e| e.to_string()),
+        Err(payload) => Err(match payload.downcast_ref::<&str>() {
+            Some(message) => format!("Panicked: {}", message),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => format!("Panicked: {}", message),
+                None => String::from("Panicked"),
+            },
+        }),
+    }
+}
+
+fn render(id: PuzzleId, evaluations: &[Evaluation], previous: &[Evaluation]) -> String {
+    let mut output = format!("Watching {}, press Ctrl-C to stop\n", id);
+
+    for evaluation in evaluations {
+        output.push_str(&format!("\n{}\n", evaluation.path.display()));
+
+        match &evaluation.summary {
+            Ok(summary) => output.push_str(&format!("  {}\n", summary)),
+            Err(e) => output.push_str(&format!("  Error: {}\n", e)),
+        }
+
+        let before = previous
+            .iter()
+            .find(|before| before.path == evaluation.path);
+
+        for (index, (answer, elapsed)) in evaluation.parts.iter().enumerate() {
+            let before = before.and_then(|before| before.parts.get(index));
+            let answer = describe(answer);
+            let timing = format!("{:.3} ms", elapsed.as_secs_f64() * 1000.0);
+
+            output.push_str(&format!("  part {}: ", index + 1));
+
+            match before.map(|(before, _)| describe(before)) {
+                Some(before) if before != answer => {
+                    output.push_str(&show_change(&before, &answer, &timing))
+                }
+                _ => output.push_str(&format!("{} ({})\n", answer, timing)),
+            }
+        }
+    }
+
+    output
+}
+
+fn describe(answer: &Result<String, String>) -> String {
+    match answer {
+        Ok(answer) => answer.clone(),
+        Err(e) => format!("Error: {}", e),
+    }
+}
+
+// Short answers show what they were, longer ones show which lines changed.
+fn show_change(before: &str, answer: &str, timing: &str) -> String {
+    if !before.contains('\n') && !answer.contains('\n') {
+        return format!("{} (was {}) ({})\n", answer, before, timing);
+    }
+
+    let before: Vec<_> = before.lines().collect();
+    let answer: Vec<_> = answer.lines().collect();
+    let mut output = format!("changed ({})\n", timing);
+
+    for index in 0..before.len().max(answer.len()) {
+        match (before.get(index), answer.get(index)) {
+            (Some(old), Some(new)) if old == new => output.push_str(&format!("      {}\n", new)),
+            (old, new) => {
+                if let Some(old) = old {
+                    output.push_str(&format!("    - {}\n", old));
+                }
+
+                if let Some(new) = new {
+                    output.push_str(&format!("    + {}\n", new));
+                }
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn evaluation(path: &str, answers: &[&str]) -> Evaluation {
+        Evaluation {
+            path: PathBuf::from(path),
+            summary: Ok(String::from("3 elves")),
+            parts: answers
+                .iter()
+                .map(|answer| (Ok(answer.to_string()), Duration::from_millis(2)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn arguments() {
+        let options = parse_args(&args(&["2022/1"])).unwrap();
+        assert_eq!(options.paths.len(), 2);
+        assert!(options.paths[1].ends_with("src/day_1/example.txt"));
+        assert_eq!(options.interval, DEFAULT_INTERVAL);
+
+        let options = parse_args(&args(&["1", "--input", "a", "--interval", "50"])).unwrap();
+        assert_eq!(options.paths, vec![PathBuf::from("a")]);
+        assert_eq!(options.interval, Duration::from_millis(50));
+
+        assert!(matches!(parse_args(&args(&[])), Err(CliError::Usage)));
+    }
+
+    #[test]
+    fn changes() {
+        let previous = vec![evaluation("input", &["24000", "a\nb"])];
+        let current = vec![evaluation("input", &["24000", "a\nc"])];
+
+        assert_eq!(
+            render(PuzzleId::new(2022, 1), &current, &previous),
+            "Watching 2022/1, press Ctrl-C to stop
+
+input
+  3 elves
+  part 1: 24000 (2.000 ms)
+  part 2: changed (2.000 ms)
+      a
+    - b
+    + c
+"
+        );
+
+        let current = vec![evaluation("input", &["45000"])];
+        assert!(render(PuzzleId::new(2022, 1), &current, &previous)
+            .contains("part 1: 45000 (was 24000) (2.000 ms)"));
+    }
+
+    #[test]
+    fn panics() {
+        assert_eq!(
+            catch(|| panic!("no elves")),
+            Err(String::from("Panicked: no elves"))
+        );
+        assert_eq!(
+            catch(|| Ok(String::from("24000"))),
+            Ok(String::from("24000"))
+        );
+    }
+
+    #[test]
+    fn stamps() {
+        assert_eq!(stamp(Path::new("no such file")), None);
+        assert!(stamp(Path::new("Cargo.toml")).is_some());
+    }
+}
@@ -0,0 +1,140 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::Day;
+
+const CACHE_DIR: &str = ".aoc_cache";
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn open_default() -> Self {
+        Self::new(
+            env::var_os("AOC_CACHE_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(CACHE_DIR)),
+        )
+    }
+
+    pub fn get(&self, day: &Day, part: u8, input: &str) -> Option<String> {
+        fs::read_to_string(self.path(day, part, input)).ok()
+    }
+
+    pub fn put(&self, day: &Day, part: u8, input: &str, answer: &str) -> io::Result<()> {
+        let path = self.path(day, part, input);
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, answer)
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    // Every implementation of a day has its own answers, as they may disagree.
+    fn path(&self, day: &Day, part: u8, input: &str) -> PathBuf {
+        self.dir
+            .join(day.year().to_string())
+            .join(day.day().to_string())
+            .join(escape(day.name()))
+            .join(format!(
+                "part_{}-v{}-{:016x}",
+                part,
+                day.version(),
+                hash(input.as_bytes())
+            ))
+    }
+}
+
+// Keeps ASCII letters and digits and writes every other byte as `_` and two hex
+// digits, so that different names never share a directory.
+fn escape(name: &str) -> String {
+    name.bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() {
+                (byte as char).to_string()
+            } else {
+                format!("_{:02x}", byte)
+            }
+        })
+        .collect()
+}
+
+// 64-bit FNV-1a, which unlike the std hashers is stable across releases.
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+    use crate::{get_day, get_implementations, PuzzleId};
+
+    // Every test has its own directory, which no other test run shares.
+    fn temp_cache(test: &str) -> Cache {
+        Cache::new(env::temp_dir().join(format!("aoc_cache_{}_{}", test, process::id())))
+    }
+
+    #[test]
+    fn get_and_put() {
+        let cache = temp_cache("get_and_put");
+        let day = get_day(1).unwrap();
+
+        cache.clear().unwrap();
+        assert_eq!(cache.get(&day, 1, "1000"), None);
+
+        cache.put(&day, 1, "1000", "1000").unwrap();
+        assert_eq!(cache.get(&day, 1, "1000").unwrap(), "1000");
+        assert_eq!(cache.get(&day, 2, "1000"), None);
+        assert_eq!(cache.get(&day, 1, "2000"), None);
+
+        cache.clear().unwrap();
+        assert_eq!(cache.get(&day, 1, "1000"), None);
+    }
+
+    #[test]
+    fn implementations() {
+        let cache = temp_cache("implementations");
+        let days = get_implementations(PuzzleId::new(2022, 20));
+
+        cache.clear().unwrap();
+        cache.put(&days[0], 1, "0", "0").unwrap();
+        assert_eq!(days[1].name(), "fast");
+        assert_eq!(cache.get(&days[1], 1, "0"), None);
+
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape("fast"), "fast");
+        assert_eq!(escape("a-b"), "a_2db");
+        assert_eq!(escape("a_b"), "a_5fb");
+        assert_eq!(escape("é"), "_c3_a9");
+    }
+
+    #[test]
+    fn stable_hash() {
+        assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::hash::Hash;
+
+// A simulation repeats once it reaches a state it has been in before: after
+// `prefix` steps it goes through the same `length` states over and over.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The first step whose state is the same as the one after `step` steps.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.length
+        }
+    }
+
+    // Extrapolates a quantity that changes by the same amount every cycle, such
+    // as the height of a tower, from its values after 0 to `prefix + length`
+    // steps. Returns None if there are too few values or the result overflows.
+    pub fn extrapolate(&self, values: &[i64], step: usize) -> Option<i64> {
+        if let Some(value) = values.get(step) {
+            return Some(*value);
+        }
+
+        let growth = values.get(self.prefix + self.length)? - values.get(self.prefix)?;
+        let cycles = i64::try_from((step - self.prefix) / self.length).ok()?;
+
+        growth
+            .checked_mul(cycles)?
+            .checked_add(values[self.equivalent_step(step)])
+    }
+}
+
+// Steps `state` until a fingerprint repeats, at most `max_steps` times. Two
+// states with the same fingerprint must go on to behave the same.
+pub fn find_cycle<S, K, F, G>(
+    state: &mut S,
+    mut step: F,
+    mut fingerprint: G,
+    max_steps: usize,
+) -> Option<Cycle>
+where
+    K: Eq + Hash,
+    F: FnMut(&mut S),
+    G: FnMut(&S) -> K,
+{
+    let mut seen = HashMap::new();
+
+    for steps in 0..=max_steps {
+        if let Some(prefix) = seen.insert(fingerprint(state), steps) {
+            return Some(Cycle {
+                prefix,
+                length: steps - prefix,
+            });
+        }
+
+        if steps < max_steps {
+            step(state);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finding() {
+        // 0, 1, 2, 3, 4, 5, 6, 2, 3, ... as the values wrap around to 2.
+        let mut values = vec![0];
+        let cycle = find_cycle(
+            &mut 0,
+            |n| {
+                *n = if *n == 6 { 2 } else { *n + 1 };
+                values.push(*n);
+            },
+            |n| *n,
+            100,
+        );
+
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                prefix: 2,
+                length: 5
+            })
+        );
+        assert_eq!(values, vec![0, 1, 2, 3, 4, 5, 6, 2]);
+        assert_eq!(cycle.unwrap().equivalent_step(13), 3);
+        assert_eq!(find_cycle(&mut 0, |n| *n += 1, |n| *n, 100), None);
+    }
+
+    #[test]
+    fn extrapolating() {
+        let cycle = Cycle {
+            prefix: 1,
+            length: 2,
+        };
+        // Grows by 1, then by 3 and 2 in every cycle.
+        let values = [0, 1, 4, 6];
+
+        assert_eq!(cycle.extrapolate(&values, 2), Some(4));
+        assert_eq!(cycle.extrapolate(&values, 5), Some(11));
+        assert_eq!(cycle.extrapolate(&values, 6), Some(14));
+        assert_eq!(cycle.extrapolate(&values[..3], 6), None);
+        assert_eq!(cycle.extrapolate(&values, usize::MAX), None);
+    }
+}
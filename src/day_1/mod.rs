@@ -1,36 +1,90 @@
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{arithmetic, ParseError, SolveError, Tag};
 
 type Input = Vec<u64>;
 
+// An elf's total that does not fit in a u64 makes the input invalid.
+fn add_calories(calories: u64, item: u64) -> Result<u64, ParseError> {
+    arithmetic::add(calories, item, "calories of an elf").map_err(|_| ParseError::Invalid)
+}
+
 pub struct Solver {}
 
 impl crate::Solver for Solver {
     type Input = Input;
     type Output = u64;
     const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         let elves = input
             .split("\n\n")
-            .map(|elf| elf.lines().map(|line| line.parse::<u64>()).sum())
+            .map(|elf| {
+                elf.lines()
+                    .try_fold(0, |calories, line| add_calories(calories, line.parse()?))
+            })
             .collect::<Result<_, _>>()?;
 
         Ok(elves)
     }
 
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        let mut elves = vec![];
+        let mut calories = 0;
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if line.is_empty() {
+                elves.push(calories);
+                calories = 0;
+            } else {
+                calories = add_calories(calories, line.parse()?)?;
+            }
+        }
+
+        elves.push(calories);
+
+        Ok(elves)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{} elves carrying {} to {} calories",
+            input.len(),
+            input.iter().min().unwrap_or(&0),
+            input.iter().max().unwrap_or(&0)
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         input.into_iter().max().ok_or(SolveError::EmptyInput)
     }
 
     fn part_2(mut input: Self::Input) -> Result<Self::Output, SolveError> {
         input.sort_unstable();
-        Ok(input.iter().rev().take(3).sum())
+        input.iter().rev().take(3).try_fold(0, |sum, &calories| {
+            arithmetic::add(sum, calories, "calories of the top three elves")
+        })
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|elf| format!("{elf}\n"))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::solving::Solver;
+    use crate::testing::assert_round_trip;
 
     fn get_input() -> Vec<u64> {
         vec![6000, 4000, 11000, 24000, 10000]
@@ -66,4 +120,20 @@ mod tests {
     fn part_2() {
         assert_eq!(super::Solver::part_2(get_input()).unwrap(), 45000);
     }
+
+    #[test]
+    fn large_calories() {
+        let max = u64::MAX.to_string();
+        let input = format!("{max}\n\n{max}\n1\n");
+
+        assert!(super::Solver::parse(input.clone()).is_err());
+        assert!(super::Solver::parse_reader(input.as_bytes()).is_err());
+        assert!(super::Solver::part_1(vec![u64::MAX, 1]).is_ok());
+        assert!(super::Solver::part_2(vec![u64::MAX, 1]).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| rng.vec(1..20, |rng| rng.next_u64() % 100_000));
+    }
 }
@@ -1,6 +1,7 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(Debug, PartialEq)]
 pub enum Instruction {
@@ -44,7 +45,7 @@ impl Display for Output {
         match self {
             Output::Part1(value) => write!(f, "{value}"),
             Output::Part2(value) => {
-                let _ = writeln!(f)?;
+                writeln!(f)?;
 
                 for i in 0..6 {
                     writeln!(f, "{}", &value[(i * 40)..((i + 1) * 40)])?;
@@ -62,31 +63,52 @@ impl crate::Solver for Solver {
     type Input = Vec<Instruction>;
     type Output = Output;
     const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+    const TAGS: &'static [Tag] = &[Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        input.lines().map(|line| parse_line(line)).collect()
+        input.lines().map(parse_line).collect()
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        reader.lines().map(|line| parse_line(&line?)).collect()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{} instructions taking {} cycles",
+            input.len(),
+            execute(input).len() - 1
+        )
     }
 
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         let xx = execute(&input);
 
-        Ok(Output::Part1(
-            [20, 60, 100, 140, 180, 220]
-                .iter()
-                .map(|i| i * xx[*i as usize - 1])
-                .sum(),
-        ))
+        let strengths = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .map(|i| xx.get(*i as usize - 1).map(|x| i * x))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(SolveError::InvalidInput)?;
+
+        Ok(Output::Part1(strengths.into_iter().sum()))
     }
 
     fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
         let xx = execute(&input);
 
+        // The screen needs a value of X for each of its 240 pixels.
+        if xx.len() < 240 {
+            return Err(SolveError::InvalidInput);
+        }
+
         let mut output = String::with_capacity(240);
 
-        for i in 0..240 {
+        for (i, x) in xx.iter().take(240).enumerate() {
             let current_pixel = (i as i32) % 40;
 
-            if xx[i] - 1 <= current_pixel && current_pixel <= xx[i] + 1 {
+            if x - 1 <= current_pixel && current_pixel <= x + 1 {
                 output.push('#');
             } else {
                 output.push('.');
@@ -97,6 +119,18 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Addx(value) => format!("addx {value}\n"),
+                Instruction::Noop => String::from("noop\n"),
+            })
+            .collect()
+    }
+}
+
 fn parse_line(line: &str) -> Result<Instruction, ParseError> {
     let mut splits = line.split_whitespace();
 
@@ -109,6 +143,7 @@ fn parse_line(line: &str) -> Result<Instruction, ParseError> {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::{assert_round_trip, assert_snapshot};
     use crate::Solver;
 
     use super::{Instruction, Output};
@@ -440,4 +475,32 @@ noop";
             Output::Part2(ref_output)
         );
     }
+
+    #[test]
+    fn short_program() {
+        let input = vec![Instruction::Noop, Instruction::Addx(3)];
+
+        assert!(super::Solver::part_1(input).is_err());
+        assert!(super::Solver::part_2(vec![Instruction::Noop]).is_err());
+    }
+
+    #[test]
+    fn crt() {
+        let output = super::Solver::part_2(get_input()).unwrap();
+
+        assert_snapshot("day_10_part_2", &output.to_string());
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..50, |rng| {
+                if rng.bool() {
+                    Instruction::Addx(rng.i64(-40..40) as i32)
+                } else {
+                    Instruction::Noop
+                }
+            })
+        });
+    }
 }
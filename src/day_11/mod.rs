@@ -1,163 +1,225 @@
 mod parsing;
 
-use std::sync::mpsc::{Receiver, Sender};
+use std::sync::mpsc::{channel, Receiver, Sender};
 
-use crate::{ParseError, SolveError};
+use crate::arithmetic;
+use crate::{Param, Params, ParseError, SolveError, Tag};
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    Add(usize),
+    Multiply(usize),
+    Double,
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, old: usize) -> Result<usize, SolveError> {
+        match self {
+            Operation::Add(number) => arithmetic::add(old, *number, "old + number"),
+            Operation::Multiply(number) => arithmetic::mul(old, *number, "old * number"),
+            Operation::Double => arithmetic::add(old, old, "old + old"),
+            Operation::Square => arithmetic::mul(old, old, "old * old"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Monkey {
-    items_in: Sender<usize>,
-    items_out: Receiver<usize>,
-    operation: Box<dyn Fn(usize) -> usize>,
+    items: Vec<usize>,
+    operation: Operation,
     divisor: usize,
     true_monkey: usize,
     false_monkey: usize,
 }
 
+struct Queue {
+    items_in: Sender<usize>,
+    items_out: Receiver<usize>,
+}
+
+fn get_queues(monkeys: &[Monkey]) -> Vec<Queue> {
+    monkeys
+        .iter()
+        .map(|monkey| {
+            let (items_in, items_out) = channel();
+
+            for item in monkey.items.iter() {
+                let _ = items_in.send(*item);
+            }
+
+            Queue {
+                items_in,
+                items_out,
+            }
+        })
+        .collect()
+}
+
 pub struct Solver {}
 
 impl crate::Solver for Solver {
     type Input = Vec<Monkey>;
     type Output = usize;
     const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+    const TAGS: &'static [Tag] = &[Tag::Math, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARAMS: &'static [Param] = &[Param {
+        name: "rounds",
+        description: "number of rounds (20 for part 1, 10000 for part 2)",
+    }];
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        input
+        let monkeys: Vec<_> = input
             .split("\n\n")
-            .map(|monkey| parsing::parse_monkey(monkey))
-            .collect::<Result<_, _>>()
+            .map(parsing::parse_monkey)
+            .collect::<Result<_, _>>()?;
+
+        // Monkeys throw to other monkeys that exist.
+        for (index, monkey) in monkeys.iter().enumerate() {
+            for target in [monkey.true_monkey, monkey.false_monkey] {
+                if target == index || target >= monkeys.len() {
+                    return Err(ParseError::Invalid);
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{} monkeys holding {} items",
+            input.len(),
+            input.iter().map(|monkey| monkey.items.len()).sum::<usize>()
+        )
     }
 
     fn part_1(monkeys: Self::Input) -> Result<Self::Output, SolveError> {
-        let mut inspection_counts = Vec::with_capacity(monkeys.len());
+        Self::part_1_with(monkeys, &Params::new())
+    }
 
-        for _ in 0..monkeys.len() {
-            inspection_counts.push(0);
-        }
+    fn part_2(monkeys: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_2_with(monkeys, &Params::new())
+    }
 
-        for _ in 0..20 {
+    fn part_1_with(monkeys: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        let queues = get_queues(&monkeys);
+        let mut inspection_counts = vec![0; monkeys.len()];
+
+        for _ in 0..params.get("rounds", 20)? {
             for (index, monkey) in monkeys.iter().enumerate() {
-                for mut item in monkey.items_out.try_iter() {
+                for mut item in queues[index].items_out.try_iter() {
                     inspection_counts[index] += 1;
 
-                    item = (monkey.operation)(item);
-                    item = item / 3;
+                    item = monkey.operation.apply(item)?;
+                    item /= 3;
 
                     if item % monkey.divisor == 0 {
-                        let _ = monkeys[monkey.true_monkey].items_in.send(item);
+                        let _ = queues[monkey.true_monkey].items_in.send(item);
                     } else {
-                        let _ = monkeys[monkey.false_monkey].items_in.send(item);
+                        let _ = queues[monkey.false_monkey].items_in.send(item);
                     }
                 }
             }
         }
 
-        inspection_counts.sort_unstable();
-
-        Ok(inspection_counts[inspection_counts.len() - 2]
-            * inspection_counts[inspection_counts.len() - 1])
+        monkey_business(inspection_counts)
     }
 
-    fn part_2(monkeys: Self::Input) -> Result<Self::Output, SolveError> {
-        let mut inspection_counts = Vec::with_capacity(monkeys.len());
-
-        for _ in 0..monkeys.len() {
-            inspection_counts.push(0);
-        }
+    fn part_2_with(monkeys: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        let queues = get_queues(&monkeys);
+        let mut inspection_counts = vec![0; monkeys.len()];
 
-        let super_divisor = monkeys.iter().fold(1, |acc, monkey| acc * monkey.divisor);
+        let super_divisor = monkeys.iter().try_fold(1usize, |acc, monkey| {
+            arithmetic::mul(acc, monkey.divisor, "product of the divisors")
+        })?;
 
-        for _i in 0..10_000 {
+        for _i in 0..params.get("rounds", 10_000)? {
             for (index, monkey) in monkeys.iter().enumerate() {
-                let items = monkey.items_out.try_iter().collect::<Vec<_>>();
+                let items = queues[index].items_out.try_iter().collect::<Vec<_>>();
 
                 for mut item in items {
                     inspection_counts[index] += 1;
 
-                    item = (monkey.operation)(item);
+                    item = monkey.operation.apply(item)?;
 
                     let remainder = item % monkey.divisor;
                     let item = item % super_divisor;
 
                     if remainder == 0 {
-                        let _ = monkeys[monkey.true_monkey].items_in.send(item);
+                        let _ = queues[monkey.true_monkey].items_in.send(item);
                     } else {
-                        let _ = monkeys[monkey.false_monkey].items_in.send(item);
+                        let _ = queues[monkey.false_monkey].items_in.send(item);
                     }
                 }
             }
         }
 
-        inspection_counts.sort_unstable();
+        monkey_business(inspection_counts)
+    }
+}
 
-        Ok(inspection_counts[inspection_counts.len() - 2]
-            * inspection_counts[inspection_counts.len() - 1])
+// The product of the two highest inspection counts.
+fn monkey_business(mut inspection_counts: Vec<usize>) -> Result<usize, SolveError> {
+    inspection_counts.sort_unstable();
+
+    match inspection_counts[..] {
+        [.., second, first] => arithmetic::mul(second, first, "monkey business"),
+        _ => Err(SolveError::InvalidInput),
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .enumerate()
+            .map(|(index, monkey)| parsing::write_monkey(index, monkey))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::mpsc::channel;
-
-    use crate::Solver;
+    use crate::testing::assert_round_trip;
+    use crate::{Solver, Writer};
 
     use super::*;
 
     fn get_input() -> Vec<Monkey> {
-        let (t0, r0) = channel::<usize>();
-        let (t1, r1) = channel::<usize>();
-        let (t2, r2) = channel::<usize>();
-        let (t3, r3) = channel::<usize>();
-
-        let monkeys = vec![
+        vec![
             Monkey {
-                items_in: t0,
-                items_out: r0,
-                operation: Box::new(|old| old * 19),
+                items: vec![79, 98],
+                operation: Operation::Multiply(19),
                 divisor: 23,
                 true_monkey: 2,
                 false_monkey: 3,
             },
             Monkey {
-                items_in: t1,
-                items_out: r1,
-                operation: Box::new(|old| old + 6),
+                items: vec![54, 65, 75, 74],
+                operation: Operation::Add(6),
                 divisor: 19,
                 true_monkey: 2,
                 false_monkey: 0,
             },
             Monkey {
-                items_in: t2,
-                items_out: r2,
-                operation: Box::new(|old| old * old),
+                items: vec![79, 60, 97],
+                operation: Operation::Square,
                 divisor: 13,
                 true_monkey: 1,
                 false_monkey: 3,
             },
             Monkey {
-                items_in: t3,
-                items_out: r3,
-                operation: Box::new(|old| old + 3),
+                items: vec![74],
+                operation: Operation::Add(3),
                 divisor: 17,
                 true_monkey: 0,
                 false_monkey: 1,
             },
-        ];
-
-        let _ = monkeys[0].items_in.send(79);
-        let _ = monkeys[0].items_in.send(98);
-
-        let _ = monkeys[1].items_in.send(54);
-        let _ = monkeys[1].items_in.send(65);
-        let _ = monkeys[1].items_in.send(75);
-        let _ = monkeys[1].items_in.send(74);
-
-        let _ = monkeys[2].items_in.send(79);
-        let _ = monkeys[2].items_in.send(60);
-        let _ = monkeys[2].items_in.send(97);
-
-        let _ = monkeys[3].items_in.send(74);
-
-        monkeys
+        ]
     }
 
     #[test]
@@ -190,41 +252,13 @@ Monkey 3:
     If true: throw to monkey 0
     If false: throw to monkey 1";
 
-        let mut monkeys = super::Solver::parse(String::from(input)).unwrap();
-
-        let monkey3 = monkeys.pop().unwrap();
-        let monkey2 = monkeys.pop().unwrap();
-        let monkey1 = monkeys.pop().unwrap();
-        let monkey0 = monkeys.pop().unwrap();
+        let monkeys = super::Solver::parse(String::from(input)).unwrap();
 
-        let items0 = vec![79, 98];
-        let items1 = vec![54, 65, 75, 74];
-        let items2 = vec![79, 60, 97];
-        let items3 = vec![74];
-
-        assert_eq!(monkey0.items_out.try_iter().collect::<Vec<_>>(), items0);
-        assert_eq!((monkey0.operation)(79), 1501);
-        assert_eq!(monkey0.divisor, 23);
-        assert_eq!(monkey0.true_monkey, 2);
-        assert_eq!(monkey0.false_monkey, 3);
-
-        assert_eq!(monkey1.items_out.try_iter().collect::<Vec<_>>(), items1);
-        assert_eq!((monkey1.operation)(54), 60);
-        assert_eq!(monkey1.divisor, 19);
-        assert_eq!(monkey1.true_monkey, 2);
-        assert_eq!(monkey1.false_monkey, 0);
-
-        assert_eq!(monkey2.items_out.try_iter().collect::<Vec<_>>(), items2,);
-        assert_eq!((monkey2.operation)(79), 6241);
-        assert_eq!(monkey2.divisor, 13);
-        assert_eq!(monkey2.true_monkey, 1);
-        assert_eq!(monkey2.false_monkey, 3);
-
-        assert_eq!(monkey3.items_out.try_iter().collect::<Vec<_>>(), items3);
-        assert_eq!((monkey3.operation)(74), 77);
-        assert_eq!(monkey3.divisor, 17);
-        assert_eq!(monkey3.true_monkey, 0);
-        assert_eq!(monkey3.false_monkey, 1);
+        assert_eq!(monkeys, get_input());
+        assert_eq!(monkeys[0].operation.apply(79).unwrap(), 1501);
+        assert_eq!(monkeys[1].operation.apply(54).unwrap(), 60);
+        assert_eq!(monkeys[2].operation.apply(79).unwrap(), 6241);
+        assert_eq!(monkeys[3].operation.apply(74).unwrap(), 77);
     }
 
     #[test]
@@ -240,4 +274,54 @@ Monkey 3:
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 2713310158);
     }
+
+    #[test]
+    fn rounds() {
+        let mut params = Params::new();
+        params.set("rounds", 1);
+
+        assert_eq!(
+            super::Solver::part_1_with(get_input(), &params).unwrap(),
+            5 * 4
+        );
+        assert_eq!(
+            super::Solver::part_2_with(get_input(), &params).unwrap(),
+            4 * 6
+        );
+    }
+
+    #[test]
+    fn invalid_monkeys() {
+        assert!(monkey_business(vec![5]).is_err());
+
+        let input = super::Solver::write(&get_input());
+        let input = input.replace("throw to monkey 3", "throw to monkey 4");
+        assert!(super::Solver::parse(input).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let operations = [
+            Operation::Add(1),
+            Operation::Add(8),
+            Operation::Multiply(3),
+            Operation::Multiply(19),
+            Operation::Double,
+            Operation::Square,
+        ];
+
+        assert_round_trip::<super::Solver>(|rng| {
+            let num_monkeys = rng.usize(2..10);
+
+            (0..num_monkeys)
+                .map(|index| Monkey {
+                    items: rng.vec(0..6, |rng| rng.usize(1..100)),
+                    operation: *rng.choose(&operations),
+                    divisor: rng.usize(2..30),
+                    true_monkey: (index + rng.usize(1..num_monkeys)) % num_monkeys,
+                    false_monkey: (index + rng.usize(1..num_monkeys)) % num_monkeys,
+                })
+                .collect()
+        });
+    }
 }
@@ -1,8 +1,6 @@
-use std::sync::mpsc::{channel, Receiver, Sender};
-
 use crate::ParseError;
 
-use super::Monkey;
+use super::{Monkey, Operation};
 
 pub fn parse_monkey(input: &str) -> Result<Monkey, ParseError> {
     let mut lines = input.lines();
@@ -13,11 +11,8 @@ pub fn parse_monkey(input: &str) -> Result<Monkey, ParseError> {
     let true_line = lines.next().ok_or(ParseError::Incomplete)?;
     let false_line = lines.next().ok_or(ParseError::Incomplete)?;
 
-    let (tx, rx) = parse_items(items_line)?;
-
     Ok(Monkey {
-        items_in: tx,
-        items_out: rx,
+        items: parse_items(items_line)?,
         operation: parse_operation(operation_line)?,
         divisor: parse_divisor(test_line)?,
         true_monkey: parse_true_monkey(true_line)?,
@@ -25,45 +20,59 @@ pub fn parse_monkey(input: &str) -> Result<Monkey, ParseError> {
     })
 }
 
-fn parse_items(items: &str) -> Result<(Sender<usize>, Receiver<usize>), ParseError> {
-    let (tx, rx) = channel::<usize>();
+pub fn write_monkey(index: usize, monkey: &Monkey) -> String {
+    let items = monkey
+        .items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let operation = match monkey.operation {
+        Operation::Add(number) => format!("+ {number}"),
+        Operation::Multiply(number) => format!("* {number}"),
+        Operation::Double => String::from("+ old"),
+        Operation::Square => String::from("* old"),
+    };
+
+    format!(
+        "Monkey {index}:
+  Starting items: {items}
+  Operation: new = old {operation}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+        monkey.divisor, monkey.true_monkey, monkey.false_monkey
+    )
+}
 
+fn parse_items(items: &str) -> Result<Vec<usize>, ParseError> {
     let items = items
         .strip_prefix("  Starting items:")
         .ok_or(ParseError::Invalid)?;
 
-    for item in items.split(',') {
-        let _ = tx.send(item.trim().parse()?);
-    }
-
-    Ok((tx, rx))
+    items
+        .split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| Ok(item.parse()?))
+        .collect()
 }
 
-fn parse_operation(operation: &str) -> Result<Box<dyn Fn(usize) -> usize>, ParseError> {
+fn parse_operation(operation: &str) -> Result<Operation, ParseError> {
     let operation = operation
         .strip_prefix("  Operation: new = old ")
         .ok_or(ParseError::Invalid)?;
 
-    let op = operation.chars().next().ok_or(ParseError::Incomplete)?;
-
-    if &operation[2..] == "old" {
-        if op == '*' {
-            Ok(Box::new(|old| old * old))
-        } else if op == '+' {
-            Ok(Box::new(|old| old + old))
-        } else {
-            Err(ParseError::Invalid)
-        }
-    } else {
-        let number: usize = operation[2..].parse()?;
-
-        if op == '*' {
-            Ok(Box::new(move |old| old * number))
-        } else if op == '+' {
-            Ok(Box::new(move |old| old + number))
-        } else {
-            Err(ParseError::Invalid)
-        }
+    let (op, operand) = operation.split_once(' ').ok_or(ParseError::Incomplete)?;
+
+    match (op, operand) {
+        ("*", "old") => Ok(Operation::Square),
+        ("+", "old") => Ok(Operation::Double),
+        ("*", number) => Ok(Operation::Multiply(number.parse()?)),
+        ("+", number) => Ok(Operation::Add(number.parse()?)),
+        _ => Err(ParseError::Invalid),
     }
 }
 
@@ -72,7 +81,10 @@ fn parse_divisor(test: &str) -> Result<usize, ParseError> {
         .strip_prefix("  Test: divisible by ")
         .ok_or(ParseError::Invalid)?;
 
-    Ok(test.parse()?)
+    match test.parse()? {
+        0 => Err(ParseError::Invalid),
+        divisor => Ok(divisor),
+    }
 }
 
 fn parse_true_monkey(monkey: &str) -> Result<usize, ParseError> {
@@ -1,4 +1,4 @@
-use crate::{ParseError, SolveError};
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(Debug, PartialEq)]
 struct Node {
@@ -99,16 +99,35 @@ impl crate::Solver for Solver {
     type Input = Grid;
     type Output = usize;
     const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Grid, Tag::Search];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        let grid = input
+        let grid: Vec<Vec<Node>> = input
             .lines()
             .map(|line| line.chars().map(|c| c.into()).collect())
             .collect();
 
+        let is_height = |c| matches!(char::from_u32(c), Some('a'..='z' | 'S' | 'E'));
+
+        if grid.iter().any(|row| row.len() != grid[0].len())
+            || grid.iter().flatten().any(|node| !is_height(node.height))
+        {
+            return Err(ParseError::Invalid);
+        }
+
         Ok(Grid { grid })
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{}x{} grid",
+            input.grid.first().map(|row| row.len()).unwrap_or(0),
+            input.grid.len()
+        )
+    }
+
     fn part_1(mut grid: Self::Input) -> Result<Self::Output, SolveError> {
         let (x, y) = grid.find_end_node()?;
         grid.visit_neighbors(x, y);
@@ -132,8 +151,26 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .grid
+            .iter()
+            .map(|row| {
+                let mut line: String = row
+                    .iter()
+                    .filter_map(|node| char::from_u32(node.height))
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     use super::*;
@@ -207,6 +244,9 @@ abdefghi";
             super::Solver::parse(String::from(input)).unwrap(),
             get_input()
         );
+
+        assert!(super::Solver::parse(String::from("SabE\nab")).is_err());
+        assert!(super::Solver::parse(String::from("Sa#E")).is_err());
     }
 
     #[test]
@@ -222,4 +262,25 @@ abdefghi";
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 29);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let width = rng.usize(2..20);
+            let height = rng.usize(1..20);
+
+            let mut grid: Vec<Vec<_>> = (0..height)
+                .map(|_| {
+                    (0..width)
+                        .map(|_| Node::from((b'a' + rng.usize(0..26) as u8) as char))
+                        .collect()
+                })
+                .collect();
+
+            grid[rng.usize(0..height)][0] = Node::from('S');
+            grid[rng.usize(0..height)][width - 1] = Node::from('E');
+
+            Grid { grid }
+        });
+    }
 }
@@ -1,38 +1,75 @@
 use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
 
-use crate::{ParseError, SolveError};
+use crate::{ParseError, SolveError, Tag};
 
-#[derive(Clone, Debug, Eq, Ord, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Packet {
     List(Vec<Packet>),
     Int(u32),
 }
 
-impl PartialOrd for Packet {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Packet {
+    fn depth(&self) -> usize {
+        match self {
+            Packet::Int(_) => 0,
+            Packet::List(packets) => 1 + packets.iter().map(Packet::depth).max().unwrap_or(0),
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
         match self {
             Packet::Int(v1) => match other {
-                Packet::Int(v2) => v1.partial_cmp(v2),
-                Packet::List(_) => Packet::from(vec![*v1]).partial_cmp(other),
+                Packet::Int(v2) => v1.cmp(v2),
+                Packet::List(_) => Packet::from(vec![*v1]).cmp(other),
             },
             Packet::List(l1) => match other {
-                Packet::Int(v2) => self.partial_cmp(&Packet::from(vec![*v2])),
+                Packet::Int(v2) => self.cmp(&Packet::from(vec![*v2])),
                 Packet::List(l2) => {
                     for (p1, p2) in l1.iter().zip(l2.iter()) {
                         if p1 < p2 {
-                            return Some(Ordering::Less);
+                            return Ordering::Less;
                         } else if p1 > p2 {
-                            return Some(Ordering::Greater);
+                            return Ordering::Greater;
                         }
                     }
 
-                    l1.len().partial_cmp(&l2.len())
+                    l1.len().cmp(&l2.len())
                 }
             },
         }
     }
 }
 
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Packet::Int(value) => write!(f, "{value}"),
+            Packet::List(packets) => {
+                write!(f, "[")?;
+
+                for (i, packet) in packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{packet}")?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
 impl From<u32> for Packet {
     fn from(value: u32) -> Self {
         Packet::Int(value)
@@ -57,14 +94,28 @@ impl crate::Solver for Solver {
     type Input = Vec<(Packet, Packet)>;
     type Output = usize;
     const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         let lines: Vec<_> = input.lines().collect();
 
-        Ok(lines
-            .chunks(3)
-            .map(|triplet| parse_triplet(triplet))
-            .collect::<Result<_, _>>()?)
+        lines.chunks(3).map(parse_triplet).collect::<Result<_, _>>()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        let depth = input
+            .iter()
+            .map(|(first, second)| first.depth().max(second.depth()))
+            .max()
+            .unwrap_or(0);
+
+        format!(
+            "{} pairs of packets nested up to {} deep",
+            input.len(),
+            depth
+        )
     }
 
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
@@ -94,29 +145,53 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|(first, second)| format!("{first}\n{second}\n"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 fn parse_triplet(triplet: &[&str]) -> Result<(Packet, Packet), ParseError> {
-    let mut first_i = 1;
-    let first = parse_list(triplet[0], &mut first_i)?;
+    match triplet {
+        [first, second] | [first, second, ""] => Ok((parse_packet(first)?, parse_packet(second)?)),
+        [_] => Err(ParseError::Incomplete),
+        _ => Err(ParseError::Invalid),
+    }
+}
 
-    let mut second_i = 1;
-    let second = parse_list(triplet[1], &mut second_i)?;
+fn parse_packet(line: &str) -> Result<Packet, ParseError> {
+    let mut i = 1;
 
-    Ok((first, second))
+    if !line.starts_with('[') {
+        return Err(ParseError::Invalid);
+    }
+
+    let packet = parse_list(line, &mut i)?;
+
+    if i == line.len() {
+        Ok(packet)
+    } else {
+        Err(ParseError::Invalid)
+    }
 }
 
 fn parse_list(line: &str, i: &mut usize) -> Result<Packet, ParseError> {
     let mut packets = vec![];
 
-    while *i < line.len() {
-        if &line[*i..*i + 1] == "[" {
+    while let Some(&byte) = line.as_bytes().get(*i) {
+        if byte == b'[' {
             *i += 1;
 
             packets.push(parse_list(line, i)?);
-        } else if &line[*i..*i + 1] == "]" {
+        } else if byte == b']' {
             *i += 1;
 
             return Ok(Packet::List(packets));
-        } else if &line[*i..*i + 1] == "," {
+        } else if byte == b',' {
             *i += 1;
         } else {
             packets.push(parse_number(line, i)?);
@@ -155,10 +230,21 @@ fn parse_number(line: &str, i: &mut usize) -> Result<Packet, ParseError> {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::{assert_round_trip, Rng};
     use crate::Solver;
 
     use super::Packet;
 
+    fn generate_list(rng: &mut Rng, depth: usize) -> Packet {
+        Packet::List(rng.vec(0..5, |rng| {
+            if depth > 0 && rng.usize(0..3) == 0 {
+                generate_list(rng, depth - 1)
+            } else {
+                Packet::Int(rng.usize(0..11) as u32)
+            }
+        }))
+    }
+
     fn get_input() -> Vec<(Packet, Packet)> {
         vec![
             (
@@ -211,9 +297,7 @@ mod tests {
         ]
     }
 
-    #[test]
-    fn parsing() {
-        let input = r"[1,1,3,1,1]
+    const INPUT: &str = r"[1,1,3,1,1]
 [1,1,5,1,1]
 
 [[1],[2,3,4]]
@@ -237,10 +321,16 @@ mod tests {
 [1,[2,[3,[4,[5,6,7]]]],8,9]
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
 
+    #[test]
+    fn parsing() {
         assert_eq!(
-            super::Solver::parse(String::from(input)).unwrap(),
+            super::Solver::parse(String::from(INPUT)).unwrap(),
             get_input()
         );
+
+        for input in ["[1]", "[1]\n[2", "[1]\n2]", "[1]\n[2]]", "[1]\n[é]"] {
+            assert!(super::Solver::parse(String::from(input)).is_err());
+        }
     }
 
     #[test]
@@ -250,10 +340,46 @@ mod tests {
         assert_eq!(super::Solver::part_1(input).unwrap(), 13);
     }
 
+    #[test]
+    fn normalised_input() {
+        let input = format!("\u{feff}{}\r\n\r\n", INPUT.replace('\n', " \r\n"));
+
+        let (answer, records) =
+            crate::log::capture(|| crate::solve_part_1::<super::Solver>(input.clone()));
+
+        // The library normalises quietly unless the policy says otherwise.
+        assert_eq!(answer.unwrap(), 13);
+        assert!(records.is_empty());
+
+        let (normalised, records) =
+            crate::log::capture(|| crate::normalise(input, crate::NormalisePolicy::Warn));
+
+        assert_eq!(normalised.unwrap(), format!("{INPUT}\n"));
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.message.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "Normalised input with a byte order mark",
+                "Normalised input with CRLF line endings",
+                "Normalised input with trailing whitespace",
+                "Normalised input with trailing blank lines",
+            ]
+        );
+    }
+
     #[test]
     fn part_2() {
         let input = get_input();
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 140);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..10, |rng| (generate_list(rng, 4), generate_list(rng, 4)))
+        });
+    }
 }
@@ -1,7 +1,7 @@
 use std::collections::BTreeSet;
 use std::fmt::{self, Debug, Formatter};
 
-use crate::{ParseError, SolveError};
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(PartialEq)]
 pub struct Grid {
@@ -15,8 +15,8 @@ pub struct Grid {
 
 impl Grid {
     fn new(min_x: usize, max_x: usize, max_y: usize) -> Self {
-        let width = (max_x - min_x + 3) as usize;
-        let height = (max_y + 2) as usize;
+        let width = max_x - min_x + 3;
+        let height = max_y + 2;
 
         Self {
             cells: [false].repeat(width * height),
@@ -28,6 +28,61 @@ impl Grid {
         }
     }
 
+    fn from_lines(lines: &[Vec<(usize, usize)>]) -> Result<Self, ParseError> {
+        let min_x = get_min(lines, |(x, _)| *x)?;
+        let max_x = get_max(lines, |(x, _)| *x)?;
+        let max_y = get_max(lines, |(_, y)| *y)?;
+
+        // The grid keeps a column free to the left of the rock.
+        if min_x == 0 {
+            return Err(ParseError::Invalid);
+        }
+
+        let mut grid = Grid::new(min_x, max_x, max_y);
+
+        for line in lines.iter() {
+            grid.add_line(line);
+        }
+
+        Ok(grid)
+    }
+
+    fn to_lines(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut lines = vec![];
+
+        for y in self.min_y..self.min_y + self.height {
+            let mut x = self.min_x;
+
+            while x < self.min_x + self.width {
+                if self.get(x, y) {
+                    let start_x = x;
+
+                    while self.get(x + 1, y) && x + 1 < self.min_x + self.width {
+                        x += 1;
+                    }
+
+                    lines.push(vec![(start_x, y), (x, y)]);
+                }
+
+                x += 1;
+            }
+        }
+
+        let min_x = self.min_x + 1;
+        let max_x = self.min_x + self.width - 2;
+        let max_y = self.height - 2;
+
+        if get_min(&lines, |(x, _)| *x).ok() != Some(min_x)
+            || get_max(&lines, |(x, _)| *x).ok() != Some(max_x)
+            || get_max(&lines, |(_, y)| *y).ok() != Some(max_y)
+        {
+            lines.push(vec![(min_x, max_y)]);
+            lines.push(vec![(max_x, max_y)]);
+        }
+
+        lines
+    }
+
     fn get(&self, x: usize, y: usize) -> bool {
         if self.is_valid_index(x, y) {
             self.cells[x - self.min_x + (y - self.min_y) * self.width]
@@ -78,12 +133,14 @@ impl Grid {
     fn drop_sand(&mut self, x: usize, y: usize) -> Option<(usize, usize)> {
         for y in y..self.height {
             if self.get(x, y) {
-                if !self.get(x - 1, y) {
-                    return self.drop_sand(x - 1, y);
+                let left = x.checked_sub(1)?;
+
+                if !self.get(left, y) {
+                    return self.drop_sand(left, y);
                 } else if !self.get(x + 1, y) {
                     return self.drop_sand(x + 1, y);
                 } else {
-                    return Some((x, y - 1));
+                    return Some((x, y.checked_sub(1)?));
                 }
             }
         }
@@ -94,7 +151,7 @@ impl Grid {
 
 impl Debug for Grid {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        let _ = writeln!(
+        writeln!(
             f,
             "Grid {{ min_x: {}, min_y: {}, width: {}, height: {}, cells:",
             self.min_x, self.min_y, self.width, self.height
@@ -103,13 +160,13 @@ impl Debug for Grid {
         for y in self.min_y..self.min_y + self.height + 6 {
             for x in self.min_x - 20..self.min_x + self.width + 20 {
                 if self.get(x, y) {
-                    let _ = write!(f, "#")?;
+                    write!(f, "#")?;
                 } else {
-                    let _ = write!(f, ".")?;
+                    write!(f, ".")?;
                 }
             }
 
-            let _ = write!(f, "\n")?;
+            writeln!(f)?;
         }
 
         write!(f, "}}")
@@ -122,24 +179,26 @@ impl crate::Solver for Solver {
     type Input = Grid;
     type Output = usize;
     const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         let lines = input
             .lines()
-            .map(|line| parse_line(line))
+            .map(parse_line)
             .collect::<Result<Vec<_>, _>>()?;
 
-        let min_x = get_min(&lines, |(x, _)| *x)?;
-        let max_x = get_max(&lines, |(x, _)| *x)?;
-        let max_y = get_max(&lines, |(_, y)| *y)?;
-
-        let mut grid = Grid::new(min_x, max_x, max_y);
-
-        for line in lines.iter() {
-            grid.add_line(line);
-        }
+        Grid::from_lines(&lines)
+    }
 
-        Ok(grid)
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "rock between x={} and x={}, down to y={}",
+            input.min_x + 1,
+            input.min_x + input.width - 2,
+            input.height - 2
+        )
     }
 
     fn part_1(mut grid: Self::Input) -> Result<Self::Output, SolveError> {
@@ -170,7 +229,76 @@ impl crate::Solver for Solver {
     }
 }
 
-fn get_min<F>(coords: &Vec<Vec<(usize, usize)>>, chooser: F) -> Result<usize, ParseError>
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .to_lines()
+            .iter()
+            .map(|line| {
+                let coords: Vec<_> = line.iter().map(|(x, y)| format!("{x},{y}")).collect();
+                format!("{}\n", coords.join(" -> "))
+            })
+            .collect()
+    }
+}
+
+pub struct Sand {
+    grid: Grid,
+    grains: BTreeSet<(usize, usize)>,
+}
+
+impl crate::Simulation for Solver {
+    type State = Sand;
+
+    fn start(grid: Self::Input) -> Self::State {
+        Sand {
+            grid,
+            grains: BTreeSet::new(),
+        }
+    }
+
+    fn step(sand: &mut Self::State) -> bool {
+        if sand.grains.contains(&(500, 0)) {
+            return false;
+        }
+
+        match sand.grid.add_sand() {
+            Some(grain) => sand.grains.insert(grain),
+            None => false,
+        }
+    }
+
+    fn render(sand: &Self::State) -> String {
+        let xs = sand.grains.iter().map(|(x, _)| *x);
+        let min_x = xs.clone().min().unwrap_or(500).min(sand.grid.min_x);
+        let max_x = xs
+            .max()
+            .unwrap_or(500)
+            .max(sand.grid.min_x + sand.grid.width - 1);
+
+        (0..sand.grid.height)
+            .map(|y| {
+                let mut line: String = (min_x..=max_x)
+                    .map(|x| {
+                        if sand.grains.contains(&(x, y)) {
+                            'o'
+                        } else if sand.grid.get(x, y) {
+                            '#'
+                        } else if (x, y) == (500, 0) {
+                            '+'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+fn get_min<F>(coords: &[Vec<(usize, usize)>], chooser: F) -> Result<usize, ParseError>
 where
     F: Fn(&(usize, usize)) -> usize,
 {
@@ -182,7 +310,7 @@ where
         .ok_or(ParseError::Incomplete)
 }
 
-fn get_max<F>(coords: &Vec<Vec<(usize, usize)>>, chooser: F) -> Result<usize, ParseError>
+fn get_max<F>(coords: &[Vec<(usize, usize)>], chooser: F) -> Result<usize, ParseError>
 where
     F: Fn(&(usize, usize)) -> usize,
 {
@@ -196,7 +324,7 @@ where
 
 fn parse_line(line: &str) -> Result<Vec<(usize, usize)>, ParseError> {
     line.split(" -> ")
-        .map(|coord| parse_coord(coord))
+        .map(parse_coord)
         .collect::<Result<_, _>>()
 }
 
@@ -211,7 +339,8 @@ fn parse_coord(coord: &str) -> Result<(usize, usize), ParseError> {
 mod tests {
     use std::collections::BTreeSet;
 
-    use crate::Solver;
+    use crate::testing::{assert_round_trip, assert_snapshot};
+    use crate::{Simulation, Solver};
 
     use super::Grid;
 
@@ -258,6 +387,8 @@ mod tests {
             super::Solver::parse(String::from(input)).unwrap(),
             get_input()
         );
+
+        assert!(super::Solver::parse(String::from("0,4 -> 2,4")).is_err());
     }
 
     #[test]
@@ -272,6 +403,26 @@ mod tests {
         let input = get_input();
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 93);
+
+        let input = super::Solver::parse(String::from("500,0 -> 501,0")).unwrap();
+        assert!(super::Solver::part_2(input).is_err());
+    }
+
+    #[test]
+    fn simulation() {
+        let mut sand = super::Solver::start(get_input());
+
+        assert!(super::Solver::step(&mut sand));
+        assert!(super::Solver::step(&mut sand));
+        assert_eq!(
+            super::Solver::render(&sand).lines().nth(8).unwrap(),
+            "......oo.#.."
+        );
+
+        while super::Solver::step(&mut sand) {}
+
+        assert_eq!(sand.grains.len(), 93);
+        assert_snapshot("day_14_sand", &super::Solver::render(&sand));
     }
 
     #[test]
@@ -290,4 +441,28 @@ mod tests {
 
         assert_eq!(super::Solver::part_1(grid).unwrap(), 0);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let lines = rng.vec(1..6, |rng| {
+                let mut x = rng.usize(480..520);
+                let mut y = rng.usize(0..20);
+
+                rng.vec(1..5, |rng| {
+                    let point = (x, y);
+
+                    if rng.bool() {
+                        x = rng.usize(480..520);
+                    } else {
+                        y = rng.usize(0..20);
+                    }
+
+                    point
+                })
+            });
+
+            Grid::from_lines(&lines).unwrap()
+        });
+    }
 }
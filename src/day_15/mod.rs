@@ -1,6 +1,6 @@
 use std::collections::{BTreeSet, HashSet};
 
-use crate::{ParseError, SolveError};
+use crate::{Param, Params, ParseError, SolveError, Tag};
 
 pub type Point = (i64, i64);
 
@@ -38,20 +38,53 @@ impl<const Y: i64, const MAX: i64> crate::Solver for SolverWithParams<Y, MAX> {
     type Input = Vec<Sensor>;
     type Output = usize;
     const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Math];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "row",
+            description: "row to scan in part 1 (2000000)",
+        },
+        Param {
+            name: "max",
+            description: "largest coordinate of the distress beacon in part 2 (4000000)",
+        },
+    ];
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        input
-            .lines()
-            .map(|line| parse_line(line))
-            .collect::<Result<_, _>>()
+        input.lines().map(parse_line).collect::<Result<_, _>>()
+    }
+
+    fn summarise(sensors: &Self::Input) -> String {
+        let xs = sensors.iter().map(|sensor| sensor.position.0);
+        let ys = sensors.iter().map(|sensor| sensor.position.1);
+
+        format!(
+            "{} sensors between x={}..={} and y={}..={}",
+            sensors.len(),
+            xs.clone().min().unwrap_or(0),
+            xs.max().unwrap_or(0),
+            ys.clone().min().unwrap_or(0),
+            ys.max().unwrap_or(0)
+        )
     }
 
     fn part_1(sensors: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_1_with(sensors, &Params::new())
+    }
+
+    fn part_2(sensors: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_2_with(sensors, &Params::new())
+    }
+
+    fn part_1_with(sensors: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        let y = params.get("row", Y)?;
         let mut points = BTreeSet::new();
 
         for sensor in sensors.iter() {
             for x in -sensor.nearest_distance..sensor.nearest_distance {
-                let point = (sensor.position.0 + x, Y);
+                let point = (sensor.position.0 + x, y);
 
                 if get_distance(&sensor.position, &point) <= sensor.nearest_distance {
                     let _ = points.insert(point);
@@ -61,29 +94,30 @@ impl<const Y: i64, const MAX: i64> crate::Solver for SolverWithParams<Y, MAX> {
 
         let beacons: BTreeSet<_> = sensors.iter().map(|sensor| sensor.nearest_beacon).collect();
 
-        Ok(points.difference(&beacons).filter(|p| p.1 == Y).count())
+        Ok(points.difference(&beacons).filter(|p| p.1 == y).count())
     }
 
-    fn part_2(sensors: Self::Input) -> Result<Self::Output, SolveError> {
+    fn part_2_with(sensors: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        let max = params.get("max", MAX)?;
         let mut beacon_positions = HashSet::new();
 
         for sensor in sensors.iter() {
             let dist = sensor.nearest_distance + 1;
             let x_start = 0.max(sensor.position.0 - dist);
-            let x_end = MAX.min(sensor.position.0 + dist);
+            let x_end = max.min(sensor.position.0 + dist);
 
             for x in x_start..=x_end {
                 let diff = (sensor.position.0 - x).abs();
 
                 let p1 = (x, sensor.position.1 + dist - diff);
 
-                if is_out_of_range(&sensors, &p1, MAX) {
+                if is_out_of_range(&sensors, &p1, max) {
                     let _ = beacon_positions.insert(p1);
                 }
 
                 let p2 = (x, sensor.position.1 - dist + diff);
 
-                if is_out_of_range(&sensors, &p2, MAX) {
+                if is_out_of_range(&sensors, &p2, max) {
                     let _ = beacon_positions.insert(p2);
                 }
             }
@@ -98,6 +132,23 @@ impl<const Y: i64, const MAX: i64> crate::Solver for SolverWithParams<Y, MAX> {
     }
 }
 
+impl<const Y: i64, const MAX: i64> crate::Writer for SolverWithParams<Y, MAX> {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|sensor| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.position.0,
+                    sensor.position.1,
+                    sensor.nearest_beacon.0,
+                    sensor.nearest_beacon.1
+                )
+            })
+            .collect()
+    }
+}
+
 fn is_out_of_range(sensors: &[Sensor], point: &Point, max: i64) -> bool {
     (0..=max).contains(&point.1)
         && sensors
@@ -126,7 +177,8 @@ fn parse_line(line: &str) -> Result<Sensor, ParseError> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Solver;
+    use crate::testing::assert_round_trip;
+    use crate::{Params, Solver};
 
     use super::Sensor;
 
@@ -191,4 +243,32 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3";
             56000011
         );
     }
+
+    #[test]
+    fn params() {
+        let mut params = Params::new();
+        params.set("row", 10);
+        params.set("max", 20);
+
+        assert_eq!(
+            super::Solver::part_1_with(get_input(), &params).unwrap(),
+            26
+        );
+        assert_eq!(
+            super::Solver::part_2_with(get_input(), &params).unwrap(),
+            56000011
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..20, |rng| {
+                Sensor::new(
+                    (rng.i64(-100..100), rng.i64(-100..100)),
+                    (rng.i64(-100..100), rng.i64(-100..100)),
+                )
+            })
+        });
+    }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use crate::{ParseError, SolveError};
+use crate::log::log_debug;
+use crate::{Memo, ParseError, SolveError, Tag};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Valve {
@@ -27,18 +28,90 @@ impl crate::Solver for Solver {
     type Input = Network;
     type Output = usize;
     const DAY: u8 = 16;
+    const TITLE: &'static str = "Proboscidea Volcanium";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Search];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARTS: u8 = 1;
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        Ok(input
-            .lines()
-            .map(|line| parse_line(line))
-            .collect::<Result<_, _>>()?)
+        input.lines().map(parse_line).collect::<Result<_, _>>()
+    }
+
+    fn summarise(network: &Self::Input) -> String {
+        format!(
+            "{} valves, {} with a positive flow rate",
+            network.len(),
+            network.values().filter(|valve| valve.flow_rate > 0).count()
+        )
+    }
+
+    fn validate(network: &Self::Input) -> Vec<String> {
+        let mut problems = vec![];
+
+        if !network.contains_key("AA") {
+            problems.push(String::from("Valve AA is never defined"));
+        }
+
+        let mut names: Vec<_> = network.keys().collect();
+        names.sort_unstable();
+
+        for name in names {
+            let mut tunnels: Vec<_> = network[name].tunnels.keys().collect();
+            tunnels.sort_unstable();
+
+            for tunnel in tunnels {
+                if !network.contains_key(tunnel) {
+                    problems.push(format!(
+                        "Valve {} leads to {}, which is never defined",
+                        name, tunnel
+                    ));
+                }
+            }
+        }
+
+        problems
     }
 
     fn part_1(network: Self::Input) -> Result<Self::Output, SolveError> {
+        if !Self::validate(&network).is_empty() {
+            return Err(SolveError::InvalidInput);
+        }
+
         let network = collapse(network);
+        let mut memo = Memo::new();
+        let pressure = get_max_pressure(&network, "AA", 31, &[], &mut memo);
+
+        log_debug!("Searched states: {}", memo.stats());
+
+        Ok(pressure)
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let mut names: Vec<_> = input.keys().collect();
+        names.sort_unstable();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let valve = &input[name];
 
-        Ok(get_max_pressure(&network, "AA", 31, &[]))
+                let mut tunnels: Vec<_> = valve.tunnels.keys().map(|t| t.as_str()).collect();
+                tunnels.sort_unstable();
+
+                let tunnels = if tunnels.len() == 1 {
+                    format!("tunnel leads to valve {}", tunnels[0])
+                } else {
+                    format!("tunnels lead to valves {}", tunnels.join(", "))
+                };
+
+                format!(
+                    "Valve {} has flow rate={}; {}\n",
+                    name, valve.flow_rate, tunnels
+                )
+            })
+            .collect()
     }
 }
 
@@ -81,29 +154,43 @@ fn get_transitive_connections(network: &Network, base: String) -> HashMap<String
     connections
 }
 
-fn get_max_pressure(
-    network: &Network,
-    current: &str,
+// The pressure released from a valve only depends on which valves are already
+// open, not on the order they were opened in.
+fn get_max_pressure<'a>(
+    network: &'a Network,
+    current: &'a str,
     time_left: usize,
-    opened_valves: &[String],
+    opened_valves: &[&'a str],
+    memo: &mut Memo<(&'a str, usize, Vec<&'a str>), usize>,
 ) -> usize {
-    let mut new_opened_valves = vec![current.to_owned()];
-    new_opened_valves.extend_from_slice(opened_valves);
-
-    (time_left - 1) * network[current].flow_rate
-        + network[current]
-            .tunnels
-            .iter()
-            .filter(|(name, _)| opened_valves.iter().find(|t| &t == &name).is_none())
-            .map(|(name, distance)| {
-                if time_left - 1 > *distance {
-                    get_max_pressure(network, name, time_left - 1 - distance, &new_opened_valves)
-                } else {
-                    0
-                }
-            })
-            .max()
-            .unwrap_or(0)
+    let mut state = opened_valves.to_vec();
+    state.sort_unstable();
+
+    memo.get_or_insert_with((current, time_left, state), |memo| {
+        let mut new_opened_valves = vec![current];
+        new_opened_valves.extend_from_slice(opened_valves);
+
+        (time_left - 1) * network[current].flow_rate
+            + network[current]
+                .tunnels
+                .iter()
+                .filter(|(name, _)| !opened_valves.contains(&name.as_str()))
+                .map(|(name, distance)| {
+                    if time_left - 1 > *distance {
+                        get_max_pressure(
+                            network,
+                            name,
+                            time_left - 1 - distance,
+                            &new_opened_valves,
+                            memo,
+                        )
+                    } else {
+                        0
+                    }
+                })
+                .max()
+                .unwrap_or(0)
+    })
 }
 
 fn parse_line(line: &str) -> Result<(String, Valve), ParseError> {
@@ -120,6 +207,7 @@ fn parse_line(line: &str) -> Result<(String, Valve), ParseError> {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     use super::{Network, Valve};
@@ -165,5 +253,25 @@ Valve JJ has flow rate=21; tunnel leads to valve II";
         let input = get_input();
 
         assert_eq!(super::Solver::part_1(input).unwrap(), 1651);
+
+        let mut input = get_input();
+        input.remove("BB");
+
+        assert!(super::Solver::part_1(input).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let names = rng.vec(1..15, |rng| rng.name(2..3).to_uppercase());
+
+            names
+                .iter()
+                .map(|name| {
+                    let tunnels = rng.vec(1..4, |rng| rng.choose(&names).clone());
+                    (name.clone(), Valve::new(rng.usize(0..25), tunnels.iter()))
+                })
+                .collect::<Network>()
+        });
     }
 }
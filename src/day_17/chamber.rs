@@ -31,6 +31,17 @@ impl Chamber {
             .unwrap_or(0)
     }
 
+    // How far below the top of the tower each column is blocked, up to a depth
+    // that falling rocks are not expected to reach.
+    pub fn get_profile(&self, depth: i64) -> [i64; Self::WIDTH as usize] {
+        let height = self.get_height();
+
+        self.points.each_ref().map(|yy| {
+            let top = *yy.iter().next_back().unwrap_or(&0);
+            (height - top).min(depth)
+        })
+    }
+
     pub fn can_move(&self, rock: &Rock, movement: &Point) -> bool {
         let rock = rock + movement;
 
@@ -38,7 +49,6 @@ impl Chamber {
         can_move
     }
 
-    #[allow(unused)]
     pub fn is_blocked(&self, point: &Point) -> bool {
         self.points[point.x as usize].contains(&point.y)
     }
@@ -1,54 +1,30 @@
-use crate::{ParseError, SolveError};
+use crate::log::{log_debug, log_trace};
+use crate::{find_cycle, Param, Params, ParseError, SolveError, Tag};
 
 mod chamber;
-pub mod point;
 mod rock;
 
+pub mod point {
+    pub use crate::point::Point;
+}
+
 use chamber::Chamber;
 use point::Point;
 use rock::{Rock, PATTERNS};
 
-#[allow(unused)]
-#[cfg(not(test))]
-fn print_state(chamber: &Chamber, rock: Option<&Rock>) {
-    for y in (1..=chamber.get_height() + 6).rev() {
-        print!("|");
-
-        for x in 0..Chamber::WIDTH {
-            if rock.is_some()
-                && rock
-                    .unwrap()
-                    .get_points()
-                    .find(|p| p.x == x && p.y == y)
-                    .is_some()
-            {
-                print!("@");
-            } else if chamber.is_blocked(&Point::new(x, y)) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-
-        println!("|");
-    }
-
-    println!("+-------+");
-
-    let mut input = String::new();
-    let _ = std::io::stdin().read_line(&mut input);
-}
-
-#[allow(unused)]
-#[cfg(test)]
-fn print_state(_: &Chamber, _: Option<&Rock>) {}
-
 pub struct Solver {}
 
 impl crate::Solver for Solver {
     type Input = Vec<Point>;
     type Output = i64;
     const DAY: u8 = 17;
+    const TITLE: &'static str = "Pyroclastic Flow";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARAMS: &'static [Param] = &[Param {
+        name: "rocks",
+        description: "number of rocks to drop (2022 in part 1, 1000000000000 in part 2)",
+    }];
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         Ok(input
@@ -61,37 +37,211 @@ impl crate::Solver for Solver {
             .collect())
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        let left = input
+            .iter()
+            .filter(|movement| **movement == Point::LEFT)
+            .count();
+
+        format!(
+            "{} jets, {} pushing left and {} pushing right",
+            input.len(),
+            left,
+            input.len() - left
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_1_with(input, &Params::new())
+    }
+
+    fn part_1_with(input: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        if input.is_empty() {
+            return Err(SolveError::EmptyInput);
+        }
+
         let mut chamber = Chamber::default();
+        let mut next_movement = 0;
 
-        let mut movement_iter = input.iter().cycle();
+        for (rock, pattern) in PATTERNS
+            .iter()
+            .cycle()
+            .take(params.get("rocks", 2022)?)
+            .enumerate()
+        {
+            drop_rock(&mut chamber, pattern, &input, &mut next_movement);
 
-        for pattern in PATTERNS.iter().cycle().take(2022) {
-            let mut rock = chamber.spawn_rock(pattern);
+            log_trace!(
+                "Rock {} came to rest, the tower is {} high",
+                rock + 1,
+                chamber.get_height()
+            );
+        }
 
-            loop {
-                let movement = movement_iter.next().unwrap();
+        Ok(chamber.get_height())
+    }
 
-                if chamber.can_move(&rock, &movement) {
-                    rock.position += movement;
-                }
+    fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_2_with(input, &Params::new())
+    }
+
+    // Far too many rocks fall to drop them all, but the tower starts repeating
+    // once the same rock falls with the same jet onto the same surface.
+    fn part_2_with(input: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        if input.is_empty() {
+            return Err(SolveError::EmptyInput);
+        }
+
+        let rocks = params.get("rocks", 1_000_000_000_000)?;
+        let search = (PATTERNS.len() * input.len())
+            .saturating_mul(CYCLE_PASSES)
+            .min(rocks);
+        let mut tower = <Self as crate::Simulation>::start(input);
+        let mut heights = vec![0];
+
+        let cycle = find_cycle(
+            &mut tower,
+            |tower| {
+                <Self as crate::Simulation>::step(tower);
+                heights.push(tower.chamber.get_height());
+            },
+            |tower| {
+                (
+                    tower.rocks % PATTERNS.len(),
+                    tower.next_movement % tower.movements.len(),
+                    tower.chamber.get_profile(PROFILE_DEPTH),
+                )
+            },
+            search,
+        );
+
+        match cycle {
+            Some(cycle) => {
+                log_debug!(
+                    "The tower repeats every {} rocks after {} rocks",
+                    cycle.length,
+                    cycle.prefix
+                );
+
+                cycle
+                    .extrapolate(&heights, rocks)
+                    .ok_or(SolveError::InvalidInput)
+            }
+            None if search == rocks => Ok(heights[rocks]),
+            None => Err(SolveError::InvalidInput),
+        }
+    }
+}
+
+// Rocks are not expected to fall further than this below the top of the tower.
+const PROFILE_DEPTH: i64 = 64;
+
+// The tower is expected to repeat within this many passes through every
+// pairing of a rock and a jet of gas.
+const CYCLE_PASSES: usize = 4;
+
+fn drop_rock(
+    chamber: &mut Chamber,
+    pattern: &'static [Point],
+    movements: &[Point],
+    next_movement: &mut usize,
+) {
+    let mut rock = chamber.spawn_rock(pattern);
+
+    loop {
+        let movement = &movements[*next_movement % movements.len()];
+        *next_movement += 1;
+
+        if chamber.can_move(&rock, movement) {
+            rock.position += movement;
+        }
+
+        if chamber.can_move(&rock, &Point::DOWN) {
+            rock.position += Point::DOWN;
+        } else {
+            chamber.place_rock(&rock);
+            break;
+        }
+    }
+}
+
+pub struct Tower {
+    chamber: Chamber,
+    movements: Vec<Point>,
+    next_movement: usize,
+    rocks: usize,
+}
 
-                if chamber.can_move(&rock, &Point::DOWN) {
-                    rock.position += Point::DOWN;
+impl crate::Simulation for Solver {
+    type State = Tower;
+
+    fn start(movements: Self::Input) -> Self::State {
+        Tower {
+            chamber: Chamber::default(),
+            movements,
+            next_movement: 0,
+            rocks: 0,
+        }
+    }
+
+    fn step(tower: &mut Self::State) -> bool {
+        if tower.movements.is_empty() {
+            return false;
+        }
+
+        let pattern = PATTERNS[tower.rocks % PATTERNS.len()];
+        drop_rock(
+            &mut tower.chamber,
+            pattern,
+            &tower.movements,
+            &mut tower.next_movement,
+        );
+        tower.rocks += 1;
+
+        true
+    }
+
+    fn render(tower: &Self::State) -> String {
+        const ROWS: i64 = 20;
+
+        let height = tower.chamber.get_height();
+        let mut output = String::new();
+
+        for y in (1.max(height - ROWS + 1)..=height).rev() {
+            output.push('|');
+            output.extend((0..Chamber::WIDTH).map(|x| {
+                if tower.chamber.is_blocked(&Point::new(x, y)) {
+                    '#'
                 } else {
-                    chamber.place_rock(&rock);
-                    break;
+                    '.'
                 }
-            }
+            }));
+            output.push_str("|\n");
         }
 
-        Ok(chamber.get_height())
+        if height <= ROWS {
+            output.push_str("+-------+\n");
+        }
+
+        output
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let mut output: String = input
+            .iter()
+            .map(|movement| if *movement == Point::LEFT { '<' } else { '>' })
+            .collect();
+        output.push('\n');
+        output
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Solver;
+    use crate::testing::{assert_round_trip, assert_snapshot};
+    use crate::{Params, Simulation, Solver};
 
     use super::Point;
 
@@ -156,4 +306,56 @@ mod tests {
 
         assert_eq!(super::Solver::part_1(input).unwrap(), 3068);
     }
+
+    #[test]
+    fn part_2() {
+        let input = get_input();
+
+        assert_eq!(super::Solver::part_2(input).unwrap(), 1_514_285_714_288);
+
+        let mut params = Params::new();
+        params.set("rocks", 2022);
+
+        assert_eq!(
+            super::Solver::part_2_with(get_input(), &params).unwrap(),
+            3068
+        );
+    }
+
+    #[test]
+    fn simulation() {
+        let mut tower = super::Solver::start(get_input());
+
+        for _ in 0..3 {
+            assert!(super::Solver::step(&mut tower));
+        }
+
+        assert_eq!(
+            super::Solver::render(&tower),
+            "|..#....|\n|..#....|\n|####...|\n|..###..|\n|...#...|\n|..####.|\n+-------+\n"
+        );
+
+        let mut params = Params::new();
+        params.set("rocks", 3);
+
+        assert_eq!(super::Solver::part_1_with(get_input(), &params).unwrap(), 6);
+    }
+
+    #[test]
+    fn rendering() {
+        let mut tower = super::Solver::start(get_input());
+
+        for _ in 0..10 {
+            super::Solver::step(&mut tower);
+        }
+
+        assert_snapshot("day_17_tower", &super::Solver::render(&tower));
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..100, |rng| *rng.choose(&[Point::LEFT, Point::RIGHT]))
+        });
+    }
 }
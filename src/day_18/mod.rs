@@ -1,6 +1,6 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::{ParseError, SolveError};
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point {
@@ -51,12 +51,24 @@ impl crate::Solver for Solver {
     type Input = Vec<Point>;
     type Output = usize;
     const DAY: u8 = 18;
+    const TITLE: &'static str = "Boiling Boulders";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Search];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        Ok(input
-            .lines()
-            .map(|line| parse_line(line))
-            .collect::<Result<_, _>>()?)
+        input.lines().map(parse_line).collect::<Result<_, _>>()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        let grid = Grid::new(input);
+
+        format!(
+            "{} cubes within {}x{}x{}",
+            input.len(),
+            grid.width,
+            grid.height,
+            grid.depth
+        )
     }
 
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
@@ -81,6 +93,15 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|point| format!("{},{},{}\n", point.x, point.y, point.z))
+            .collect()
+    }
+}
+
 fn parse_line(line: &str) -> Result<Point, ParseError> {
     let mut splits = line.splitn(3, ',');
 
@@ -225,21 +246,21 @@ impl Display for Grid {
                 for z in 0..self.depth {
                     match self.get(&Point { x, y, z }) {
                         State::Free => {
-                            let _ = write!(f, ".")?;
+                            write!(f, ".")?;
                         }
                         State::Lava => {
-                            let _ = write!(f, "#")?;
+                            write!(f, "#")?;
                         }
                         State::Enclosed => {
-                            let _ = write!(f, "+")?;
+                            write!(f, "+")?;
                         }
                     }
                 }
 
-                let _ = writeln!(f)?;
+                writeln!(f)?;
             }
 
-            let _ = writeln!(f)?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -248,6 +269,7 @@ impl Display for Grid {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     use super::Point;
@@ -305,4 +327,15 @@ mod tests {
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 58);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..30, |rng| Point {
+                x: rng.usize(0..20),
+                y: rng.usize(0..20),
+                z: rng.usize(0..20),
+            })
+        });
+    }
 }
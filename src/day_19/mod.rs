@@ -1,8 +1,12 @@
-use std::thread;
+use crate::log::{log_debug, log_info};
+use crate::parallel;
+use crate::{arithmetic, Memo, Param, Params, ParseError, SolveError, Tag};
 
-use crate::{ParseError, SolveError};
+// Bounds the memory of each search, which runs on its own thread.
+const MEMO_CAPACITY: usize = 1 << 20;
+const UNREMEMBERED_MINUTES: usize = 14;
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 struct Resources {
     ore: usize,
     clay: usize,
@@ -18,8 +22,12 @@ pub struct Blueprint {
     geode_robot_cost: Resources,
 }
 
+// The resources, the number of each robot and the time.
+type State = (Resources, [usize; 4], usize);
+
 #[derive(Clone, Copy, Debug)]
-struct Factory<'a, const TIME_LIMIT: usize> {
+struct Factory<'a> {
+    time_limit: usize,
     resources: Resources,
     blueprint: &'a Blueprint,
     highest_ore_cost: usize,
@@ -29,9 +37,10 @@ struct Factory<'a, const TIME_LIMIT: usize> {
     geode_robots: usize,
 }
 
-impl<'a, const TIME_LIMIT: usize> Factory<'a, TIME_LIMIT> {
-    fn new(blueprint: &'a Blueprint) -> Self {
+impl<'a> Factory<'a> {
+    fn new(blueprint: &'a Blueprint, time_limit: usize) -> Self {
         Self {
+            time_limit,
             resources: Resources::default(),
             blueprint,
             highest_ore_cost: blueprint
@@ -47,26 +56,50 @@ impl<'a, const TIME_LIMIT: usize> Factory<'a, TIME_LIMIT> {
         }
     }
 
-    fn run(&self, time: usize, mut max: usize) -> usize {
-        if time == TIME_LIMIT {
+    // The same state is often reached by building the same robots in another
+    // order. Searching it again cannot beat the best result found since, as
+    // `max` only grows during the search. The last minutes are quicker to
+    // search again than to look up.
+    fn run(&self, time: usize, max: usize, memo: &mut Memo<State, usize>) -> usize {
+        if self.time_limit - time <= UNREMEMBERED_MINUTES {
+            return self.search(time, max, memo);
+        }
+
+        let state = (
+            self.resources,
+            [
+                self.ore_robots,
+                self.clay_robots,
+                self.obsidian_robots,
+                self.geode_robots,
+            ],
+            time,
+        );
+
+        memo.get_or_insert_with(state, |memo| self.search(time, max, memo))
+            .max(max)
+    }
+
+    fn search(&self, time: usize, mut max: usize, memo: &mut Memo<State, usize>) -> usize {
+        if time == self.time_limit {
             self.resources.geodes
         } else if self.can_build_geode_robot(time, max) {
-            self.build_geode_robot().run(time + 1, max)
+            self.build_geode_robot().run(time + 1, max, memo)
         } else {
             if self.can_build_obsidian_robot(time, max) {
-                max = self.build_obsidian_robot().run(time + 1, max);
+                max = self.build_obsidian_robot().run(time + 1, max, memo);
             }
 
             if self.can_build_ore_robot(time, max) {
-                max = max.max(self.build_ore_robot().run(time + 1, max));
+                max = max.max(self.build_ore_robot().run(time + 1, max, memo));
             }
 
             if self.can_build_clay_robot(time, max) {
-                max = max.max(self.build_clay_robot().run(time + 1, max));
+                max = max.max(self.build_clay_robot().run(time + 1, max, memo));
             }
 
             if self.can_build_nothing(time, max) {
-                max = max.max(self.build_nothing().run(time + 1, max));
+                max = max.max(self.build_nothing().run(time + 1, max, memo));
             }
 
             max
@@ -81,7 +114,7 @@ impl<'a, const TIME_LIMIT: usize> Factory<'a, TIME_LIMIT> {
     }
 
     fn can_beat_max(&self, time: usize, max: usize) -> bool {
-        let time_left = TIME_LIMIT - time;
+        let time_left = self.time_limit - time;
         self.resources.geodes
             + time_left * (self.geode_robots + self.geode_robots + time_left - 1) / 2
             > max
@@ -152,6 +185,15 @@ impl<'a, const TIME_LIMIT: usize> Factory<'a, TIME_LIMIT> {
     }
 }
 
+fn search(blueprint: &Blueprint, minutes: usize) -> usize {
+    let mut memo = Memo::with_capacity(MEMO_CAPACITY);
+    let geodes = Factory::new(blueprint, minutes).run(0, 0, &mut memo);
+
+    log_debug!("Searched states: {}", memo.stats());
+
+    geodes
+}
+
 fn parse_line(line: &str) -> Result<Blueprint, ParseError> {
     let numbers: Vec<usize> = line
         .split_whitespace()
@@ -184,70 +226,104 @@ fn parse_line(line: &str) -> Result<Blueprint, ParseError> {
     }
 }
 
+fn write_line(index: usize, blueprint: &Blueprint) -> String {
+    format!(
+        "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+         Each obsidian robot costs {} ore and {} clay. \
+         Each geode robot costs {} ore and {} obsidian.\n",
+        index + 1,
+        blueprint.ore_robot_cost.ore,
+        blueprint.clay_robot_cost.ore,
+        blueprint.obsidian_robot_cost.ore,
+        blueprint.obsidian_robot_cost.clay,
+        blueprint.geode_robot_cost.ore,
+        blueprint.geode_robot_cost.obsidian,
+    )
+}
+
 pub struct Solver {}
 
 impl crate::Solver for Solver {
     type Input = Vec<Blueprint>;
     type Output = usize;
     const DAY: u8 = 19;
+    const TITLE: &'static str = "Not Enough Minerals";
+    const TAGS: &'static [Tag] = &[Tag::Search];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARAMS: &'static [Param] = &[Param {
+        name: "minutes",
+        description: "minutes to collect geodes in (24 for part 1, 32 for part 2)",
+    }];
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        input
-            .lines()
-            .map(|line| parse_line(line))
-            .collect::<Result<Vec<_>, _>>()
+        input.lines().map(parse_line).collect::<Result<Vec<_>, _>>()
+    }
+
+    fn summarise(blueprints: &Self::Input) -> String {
+        format!("{} blueprints", blueprints.len())
     }
 
     fn part_1(blueprints: Self::Input) -> Result<Self::Output, SolveError> {
-        let mut handles = vec![];
+        Self::part_1_with(blueprints, &Params::new())
+    }
 
-        for (i, blueprint) in blueprints.into_iter().enumerate() {
-            handles.push(thread::spawn(move || {
-                let i = i + 1;
-                let result = Factory::<24>::new(&blueprint).run(0, 0);
+    fn part_2(blueprints: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_2_with(blueprints, &Params::new())
+    }
 
-                eprintln!("The quality level of blueprint {i} is {result}");
+    fn part_1_with(blueprints: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        let minutes = params.get("minutes", 24)?;
+        let results = parallel::map(blueprints, |blueprint| search(&blueprint, minutes));
+        let mut sum = 0;
 
-                i * result
-            }));
-        }
+        for (i, result) in results.into_iter().enumerate() {
+            let i = i + 1;
 
-        let mut sum = 0;
+            log_info!("The quality level of blueprint {i} is {result}");
 
-        for handle in handles {
-            sum += handle.join().map_err(|_| SolveError::InvalidInput)?;
+            let quality = arithmetic::mul(i, result, "quality level")?;
+            sum = arithmetic::add(sum, quality, "sum of the quality levels")?;
         }
 
         Ok(sum)
     }
 
-    fn part_2(blueprints: Self::Input) -> Result<Self::Output, SolveError> {
-        let mut handles = vec![];
+    fn part_2_with(
+        mut blueprints: Self::Input,
+        params: &Params,
+    ) -> Result<Self::Output, SolveError> {
+        let minutes = params.get("minutes", 32)?;
+        blueprints.truncate(3);
 
-        for (i, blueprint) in blueprints.into_iter().take(3).enumerate() {
-            handles.push(thread::spawn(move || {
-                let i = i + 1;
-                let result = Factory::<32>::new(&blueprint).run(0, 0);
+        let results = parallel::map(blueprints, |blueprint| search(&blueprint, minutes));
+        let mut product = 1;
 
-                eprintln!("The quality level of blueprint {i} is {result}");
+        for (i, result) in results.into_iter().enumerate() {
+            let i = i + 1;
 
-                result
-            }));
-        }
+            log_info!("The quality level of blueprint {i} is {result}");
 
-        let mut product = 1;
-
-        for handle in handles {
-            product *= handle.join().map_err(|_| SolveError::InvalidInput)?;
+            product = arithmetic::mul(product, result, "product of the geodes")?;
         }
 
         Ok(product)
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .enumerate()
+            .map(|(index, blueprint)| write_line(index, blueprint))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Solver;
+    use crate::testing::assert_round_trip;
+    use crate::{Params, Solver};
 
     use super::{Blueprint, Resources};
 
@@ -320,4 +396,41 @@ Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsid
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 56 * 62);
     }
+
+    #[test]
+    fn fewer_minutes() {
+        let mut params = Params::new();
+        params.set("minutes", 12);
+
+        assert_eq!(super::Solver::part_1_with(get_input(), &params).unwrap(), 0);
+
+        params.set("minutes", -1);
+        assert!(super::Solver::part_2_with(get_input(), &params).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..10, |rng| Blueprint {
+                ore_robot_cost: Resources {
+                    ore: rng.usize(1..5),
+                    ..Default::default()
+                },
+                clay_robot_cost: Resources {
+                    ore: rng.usize(1..5),
+                    ..Default::default()
+                },
+                obsidian_robot_cost: Resources {
+                    ore: rng.usize(1..5),
+                    clay: rng.usize(1..20),
+                    ..Default::default()
+                },
+                geode_robot_cost: Resources {
+                    ore: rng.usize(1..5),
+                    obsidian: rng.usize(1..20),
+                    ..Default::default()
+                },
+            })
+        });
+    }
 }
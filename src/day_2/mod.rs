@@ -1,6 +1,7 @@
 mod parsing;
 
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
 pub struct Solver {}
 
@@ -8,11 +9,22 @@ impl crate::Solver for Solver {
     type Input = Vec<Round>;
     type Output = u64;
     const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         parsing::parse(input)
     }
 
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        parsing::parse_reader(reader)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!("{} rounds", input.len())
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         Ok(input.iter().fold(0, |acc, round| acc + round.get_score()))
     }
@@ -25,6 +37,12 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        parsing::write(input)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Shape {
     Rock = 0,
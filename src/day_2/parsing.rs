@@ -1,15 +1,20 @@
+use crate::parsing::BufRead;
 use crate::ParseError;
 
 use super::{Round, Shape, Solver};
 
 pub fn parse(input: String) -> Result<<Solver as crate::Solver>::Input, ParseError> {
-    input.lines().map(|line| parse_line(line)).collect()
+    input.lines().map(parse_line).collect()
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<<Solver as crate::Solver>::Input, ParseError> {
+    reader.lines().map(|line| parse_line(&line?)).collect()
 }
 
 fn parse_line(line: &str) -> Result<Round, ParseError> {
     let mut chars = line.chars();
 
-    let opponent = chars.nth(0).ok_or(ParseError::Incomplete)?;
+    let opponent = chars.next().ok_or(ParseError::Incomplete)?;
     let opponent = parse_opponent_shape(opponent)?;
 
     let player = chars.nth(1).ok_or(ParseError::Incomplete)?;
@@ -18,6 +23,35 @@ fn parse_line(line: &str) -> Result<Round, ParseError> {
     Ok(Round { opponent, player })
 }
 
+pub fn write(input: &<Solver as crate::Solver>::Input) -> String {
+    input
+        .iter()
+        .map(|round| {
+            format!(
+                "{} {}\n",
+                write_opponent_shape(round.opponent),
+                write_player_shape(round.player)
+            )
+        })
+        .collect()
+}
+
+fn write_opponent_shape(shape: Shape) -> char {
+    match shape {
+        Shape::Rock => 'A',
+        Shape::Paper => 'B',
+        Shape::Scissors => 'C',
+    }
+}
+
+fn write_player_shape(shape: Shape) -> char {
+    match shape {
+        Shape::Rock => 'X',
+        Shape::Paper => 'Y',
+        Shape::Scissors => 'Z',
+    }
+}
+
 fn parse_opponent_shape(c: char) -> Result<Shape, ParseError> {
     match c {
         'A' => Ok(Shape::Rock),
@@ -39,6 +73,7 @@ fn parse_player_shape(c: char) -> Result<Shape, ParseError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assert_round_trip;
 
     #[test]
     fn test_parser() {
@@ -64,4 +99,16 @@ C Z";
 
         assert_eq!(input.unwrap(), ref_input);
     }
+
+    #[test]
+    fn round_trip() {
+        let shapes = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+        assert_round_trip::<Solver>(|rng| {
+            rng.vec(0..20, |rng| Round {
+                opponent: *rng.choose(&shapes),
+                player: *rng.choose(&shapes),
+            })
+        });
+    }
 }
@@ -0,0 +1,169 @@
+use crate::{Param, Params, ParseError, SolveError, Tag};
+
+pub struct Solver {}
+
+impl crate::Solver for Solver {
+    type Input = Vec<i64>;
+    type Output = i64;
+    const DAY: u8 = 20;
+    const TITLE: &'static str = <super::Solver as crate::Solver>::TITLE;
+    const TAGS: &'static [Tag] = <super::Solver as crate::Solver>::TAGS;
+    const EXAMPLE: &'static str = <super::Solver as crate::Solver>::EXAMPLE;
+    const PARTS: u8 = <super::Solver as crate::Solver>::PARTS;
+    const NAME: &'static str = "fast";
+    const PARAMS: &'static [Param] = <super::Solver as crate::Solver>::PARAMS;
+
+    fn parse(input: String) -> Result<Self::Input, ParseError> {
+        <super::Solver as crate::Solver>::parse(input)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        <super::Solver as crate::Solver>::summarise(input)
+    }
+
+    fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_1_with(input, &Params::new())
+    }
+
+    fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_2_with(input, &Params::new())
+    }
+
+    fn part_1_with(input: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        mix(input, params.get("key", 1)?, params.get("rounds", 1)?)
+    }
+
+    fn part_2_with(input: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        mix(
+            input,
+            params.get("key", 811589153)?,
+            params.get("rounds", 10)?,
+        )
+    }
+}
+
+// Keeps the indices of the numbers in blocks of about sqrt(n), so finding and
+// moving a number only shifts the numbers in one or two blocks.
+struct Blocks {
+    blocks: Vec<Vec<usize>>,
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl Blocks {
+    fn new(len: usize) -> Self {
+        let block_size = ((len as f64).sqrt() as usize).max(1);
+        let mut blocks = Self {
+            blocks: vec![],
+            block_of: vec![0; len],
+            block_size,
+        };
+        blocks.rebuild((0..len).collect());
+        blocks
+    }
+
+    fn rebuild(&mut self, order: Vec<usize>) {
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        for (block, indices) in self.blocks.iter().enumerate() {
+            for &index in indices {
+                self.block_of[index] = block;
+            }
+        }
+    }
+
+    fn order(&self) -> Vec<usize> {
+        self.blocks.iter().flatten().copied().collect()
+    }
+
+    fn remove(&mut self, index: usize) -> usize {
+        let block = self.block_of[index];
+        let offset = self.blocks[block].iter().position(|&i| i == index).unwrap();
+        let _ = self.blocks[block].remove(offset);
+
+        self.blocks[..block].iter().map(|b| b.len()).sum::<usize>() + offset
+    }
+
+    fn insert(&mut self, index: usize, mut position: usize) {
+        let last = self.blocks.len() - 1;
+
+        for (block, indices) in self.blocks.iter_mut().enumerate() {
+            if position <= indices.len() && (position < indices.len() || block == last) {
+                indices.insert(position, index);
+                self.block_of[index] = block;
+
+                if indices.len() > 2 * self.block_size {
+                    let order = self.order();
+                    self.rebuild(order);
+                }
+
+                return;
+            }
+
+            position -= indices.len();
+        }
+    }
+}
+
+fn mix(input: Vec<i64>, factor: i64, repetitions: usize) -> Result<i64, SolveError> {
+    let numbers: Vec<_> = input.into_iter().map(|num| num * factor).collect();
+    let mut blocks = Blocks::new(numbers.len());
+
+    for _ in 0..repetitions {
+        for (index, number) in numbers.iter().enumerate() {
+            let position = blocks.remove(index);
+            // A lone number has nowhere else to go.
+            let new_position = (position as i64 + number)
+                .checked_rem_euclid(numbers.len() as i64 - 1)
+                .unwrap_or(0);
+
+            blocks.insert(index, new_position as usize);
+        }
+    }
+
+    let order = blocks.order();
+    let zero = order
+        .iter()
+        .position(|&index| numbers[index] == 0)
+        .ok_or(SolveError::InvalidInput)?;
+
+    Ok([1000, 2000, 3000]
+        .iter()
+        .map(|offset| numbers[order[(zero + offset) % order.len()]])
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::assert_agree;
+    use crate::Solver;
+
+    #[test]
+    fn part_1() {
+        assert_eq!(
+            super::Solver::part_1(vec![1, 2, -3, 3, -2, 0, 4]).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(
+            super::Solver::part_2(vec![1, 2, -3, 3, -2, 0, 4]).unwrap(),
+            1623178306
+        );
+    }
+
+    #[test]
+    fn agrees_with_reference() {
+        assert_agree::<crate::day_20::Solver, super::Solver>(|rng| {
+            let mut numbers = rng.vec(1..200, |rng| rng.i64(-10_000..10_000));
+            let zero = rng.usize(0..numbers.len());
+            numbers[zero] = 0;
+            numbers
+        });
+    }
+}
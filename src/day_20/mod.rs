@@ -1,4 +1,6 @@
-use crate::{ParseError, SolveError};
+pub mod fast;
+
+use crate::{Param, Params, ParseError, SolveError, Tag};
 
 pub struct Solver {}
 
@@ -6,6 +8,19 @@ impl crate::Solver for Solver {
     type Input = Vec<i64>;
     type Output = i64;
     const DAY: u8 = 20;
+    const TITLE: &'static str = "Grove Positioning System";
+    const TAGS: &'static [Tag] = &[Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "key",
+            description: "decryption key (1 for part 1, 811589153 for part 2)",
+        },
+        Param {
+            name: "rounds",
+            description: "number of times to mix (1 for part 1, 10 for part 2)",
+        },
+    ];
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         Ok(input
@@ -14,12 +29,39 @@ impl crate::Solver for Solver {
             .collect::<Result<_, _>>()?)
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{} numbers from {} to {}",
+            input.len(),
+            input.iter().min().unwrap_or(&0),
+            input.iter().max().unwrap_or(&0)
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
-        mix(input, 1, 1)
+        Self::part_1_with(input, &Params::new())
     }
 
     fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
-        mix(input, 811589153, 10)
+        Self::part_2_with(input, &Params::new())
+    }
+
+    fn part_1_with(input: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        mix(input, params.get("key", 1)?, params.get("rounds", 1)?)
+    }
+
+    fn part_2_with(input: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
+        mix(
+            input,
+            params.get("key", 811589153)?,
+            params.get("rounds", 10)?,
+        )
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input.iter().map(|number| format!("{number}\n")).collect()
     }
 }
 
@@ -40,7 +82,10 @@ fn mix(input: Vec<i64>, factor: i64, repetitions: usize) -> Result<i64, SolveErr
 
             let _ = input.remove(position);
 
-            let new_position = (position as i64 + number).rem_euclid(input.len() as i64) as usize;
+            // A lone number has nowhere else to go.
+            let new_position = (position as i64 + number)
+                .checked_rem_euclid(input.len() as i64)
+                .unwrap_or(0) as usize;
 
             input.insert(new_position, (*index, *number));
         }
@@ -49,7 +94,7 @@ fn mix(input: Vec<i64>, factor: i64, repetitions: usize) -> Result<i64, SolveErr
     let index = input
         .iter()
         .position(|(_, number)| *number == 0)
-        .ok_or(SolveError::InvalidInput)? as usize;
+        .ok_or(SolveError::InvalidInput)?;
 
     let (_, n1) = input[(index + 1000) % input.len()];
     let (_, n2) = input[(index + 2000) % input.len()];
@@ -60,6 +105,7 @@ fn mix(input: Vec<i64>, factor: i64, repetitions: usize) -> Result<i64, SolveErr
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     fn get_input() -> Vec<i64> {
@@ -87,6 +133,7 @@ mod tests {
         let input = get_input();
 
         assert_eq!(super::Solver::part_1(input).unwrap(), 3);
+        assert_eq!(super::Solver::part_1(vec![0]).unwrap(), 0);
     }
 
     #[test]
@@ -95,4 +142,24 @@ mod tests {
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 1623178306);
     }
+
+    #[test]
+    fn params() {
+        let mut params = crate::Params::new();
+        params.set("key", 811589153);
+
+        assert_eq!(
+            super::Solver::part_1_with(get_input(), &params).unwrap(),
+            4869534918
+        );
+
+        params.set("rounds", -1);
+
+        assert!(super::Solver::part_1_with(get_input(), &params).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| rng.vec(0..50, |rng| rng.i64(-10_000..10_000)));
+    }
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::{ParseError, SolveError};
+use crate::arithmetic;
+use crate::log::log_debug;
+use crate::{Memo, ParseError, SolveError, Tag};
 
 #[derive(Debug, PartialEq)]
 pub enum Monkey {
@@ -10,9 +12,28 @@ pub enum Monkey {
 
 impl Monkey {
     pub fn get_answer(&self, monkeys: &Monkeys) -> Result<i64, SolveError> {
+        let mut memo = Memo::new();
+        let answer = self.answer(monkeys, 0, &mut memo);
+
+        log_debug!("Answers of other monkeys: {}", memo.stats());
+
+        answer
+    }
+
+    // Listening deeper than there are monkeys means that they listen in a loop.
+    fn answer<'a>(
+        &'a self,
+        monkeys: &'a Monkeys,
+        depth: usize,
+        memo: &mut Memo<&'a str, i64>,
+    ) -> Result<i64, SolveError> {
+        if depth > monkeys.len() {
+            return Err(SolveError::InvalidInput);
+        }
+
         match self {
             Monkey::Operand(answer) => Ok(*answer),
-            Monkey::Operation(op) => op.execute(&monkeys),
+            Monkey::Operation(op) => op.execute(monkeys, depth + 1, memo),
         }
     }
 }
@@ -27,14 +48,28 @@ pub struct Operation {
 }
 
 impl Operation {
-    fn execute(&self, monkeys: &Monkeys) -> Result<i64, SolveError> {
-        let a = monkeys.get(&self.a).ok_or(SolveError::InvalidInput)?;
-        let a = a.get_answer(monkeys)?;
+    // Several monkeys can listen to the same one, so its answer is remembered.
+    fn execute<'a>(
+        &'a self,
+        monkeys: &'a Monkeys,
+        depth: usize,
+        memo: &mut Memo<&'a str, i64>,
+    ) -> Result<i64, SolveError> {
+        let a = Self::listen(&self.a, monkeys, depth, memo)?;
+        let b = Self::listen(&self.b, monkeys, depth, memo)?;
 
-        let b = monkeys.get(&self.b).ok_or(SolveError::InvalidInput)?;
-        let b = b.get_answer(monkeys)?;
+        self.f.execute(a, b)
+    }
+
+    fn listen<'a>(
+        name: &'a str,
+        monkeys: &'a Monkeys,
+        depth: usize,
+        memo: &mut Memo<&'a str, i64>,
+    ) -> Result<i64, SolveError> {
+        let monkey = monkeys.get(name).ok_or(SolveError::InvalidInput)?;
 
-        Ok(self.f.execute(a, b))
+        memo.try_get_or_insert_with(name, |memo| monkey.answer(monkeys, depth, memo))
     }
 }
 
@@ -47,12 +82,12 @@ enum Function {
 }
 
 impl Function {
-    fn execute(&self, a: i64, b: i64) -> i64 {
+    fn execute(&self, a: i64, b: i64) -> Result<i64, SolveError> {
         match self {
-            Function::Add => a + b,
-            Function::Sub => a - b,
-            Function::Mul => a * b,
-            Function::Div => a / b,
+            Function::Add => arithmetic::add(a, b, "a + b"),
+            Function::Sub => arithmetic::sub(a, b, "a - b"),
+            Function::Mul => arithmetic::mul(a, b, "a * b"),
+            Function::Div => arithmetic::div(a, b, "a / b"),
         }
     }
 }
@@ -63,12 +98,53 @@ impl crate::Solver for Solver {
     type Input = Monkeys;
     type Output = i64;
     const DAY: u8 = 21;
+    const TITLE: &'static str = "Monkey Math";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Math];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARTS: u8 = 1;
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        Ok(input
-            .lines()
-            .map(|line| parse_line(line))
-            .collect::<Result<_, _>>()?)
+        input.lines().map(parse_line).collect::<Result<_, _>>()
+    }
+
+    fn summarise(monkeys: &Self::Input) -> String {
+        let operands = monkeys
+            .values()
+            .filter(|monkey| matches!(monkey, Monkey::Operand(_)))
+            .count();
+
+        format!(
+            "{} monkeys, {} yelling a number and {} an operation",
+            monkeys.len(),
+            operands,
+            monkeys.len() - operands
+        )
+    }
+
+    fn validate(monkeys: &Self::Input) -> Vec<String> {
+        let mut problems: Vec<_> = ["root", "humn"]
+            .iter()
+            .filter(|name| !monkeys.contains_key(**name))
+            .map(|name| format!("Monkey {} is never defined", name))
+            .collect();
+
+        let mut names: Vec<_> = monkeys.keys().collect();
+        names.sort_unstable();
+
+        for name in names {
+            if let Monkey::Operation(op) = &monkeys[name] {
+                for operand in [&op.a, &op.b] {
+                    if !monkeys.contains_key(operand) {
+                        problems.push(format!(
+                            "Monkey {} listens to {}, which is never defined",
+                            name, operand
+                        ));
+                    }
+                }
+            }
+        }
+
+        problems
     }
 
     fn part_1(monkeys: Self::Input) -> Result<Self::Output, SolveError> {
@@ -79,6 +155,30 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let mut names: Vec<_> = input.keys().collect();
+        names.sort_unstable();
+
+        names
+            .into_iter()
+            .map(|name| match &input[name] {
+                Monkey::Operand(operand) => format!("{name}: {operand}\n"),
+                Monkey::Operation(Operation { a, b, f }) => {
+                    let op = match f {
+                        Function::Add => '+',
+                        Function::Sub => '-',
+                        Function::Mul => '*',
+                        Function::Div => '/',
+                    };
+
+                    format!("{name}: {a} {op} {b}\n")
+                }
+            })
+            .collect()
+    }
+}
+
 fn parse_line(line: &str) -> Result<(String, Monkey), ParseError> {
     let (name, line) = line.split_once(':').ok_or(ParseError::Invalid)?;
 
@@ -108,6 +208,7 @@ fn parse_line(line: &str) -> Result<(String, Monkey), ParseError> {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     use super::*;
@@ -178,4 +279,95 @@ hmdt: 32";
 
         assert_eq!(super::Solver::part_1(input).unwrap(), 152);
     }
+
+    #[test]
+    fn division_by_zero() {
+        let input = Monkeys::from([
+            operation("root", "a", "b", Function::Div),
+            number("a", 1),
+            number("b", 0),
+        ]);
+
+        let error = super::Solver::part_1(input).unwrap_err();
+
+        if cfg!(feature = "checked") {
+            assert!(matches!(error, SolveError::DivisionByZero("a / b")));
+        } else {
+            assert!(matches!(error, SolveError::InvalidInput));
+        }
+    }
+
+    #[test]
+    fn listening_in_a_loop() {
+        let input = Monkeys::from([
+            operation("root", "a", "b", Function::Add),
+            operation("a", "root", "b", Function::Add),
+            number("b", 0),
+        ]);
+        assert!(super::Solver::part_1(input).is_err());
+    }
+
+    #[test]
+    fn shared_subtrees() {
+        let mut input: Monkeys = (0..60)
+            .map(|i| {
+                operation(
+                    &format!("m{}", i),
+                    &format!("m{}", i + 1),
+                    &format!("m{}", i + 1),
+                    Function::Add,
+                )
+            })
+            .collect();
+        input.extend([
+            number("m60", 1),
+            operation("root", "m0", "m0", Function::Sub),
+        ]);
+
+        assert_eq!(super::Solver::part_1(input).unwrap(), 0);
+    }
+
+    #[test]
+    fn validate() {
+        let mut input = get_input();
+        assert!(super::Solver::validate(&input).is_empty());
+
+        input.remove("humn");
+
+        assert_eq!(
+            super::Solver::validate(&input),
+            vec![
+                "Monkey humn is never defined",
+                "Monkey ptdq listens to humn, which is never defined"
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let names = rng.vec(1..20, |rng| rng.name(4..5));
+
+            names
+                .iter()
+                .map(|name| {
+                    if rng.bool() {
+                        number(name, rng.i64(-100..100))
+                    } else {
+                        let f = match rng.usize(0..4) {
+                            0 => Function::Add,
+                            1 => Function::Sub,
+                            2 => Function::Mul,
+                            _ => Function::Div,
+                        };
+
+                        let a = rng.choose(&names).clone();
+                        let b = rng.choose(&names).clone();
+
+                        operation(name, &a, &b, f)
+                    }
+                })
+                .collect()
+        });
+    }
 }
@@ -1,8 +1,9 @@
 use std::convert::{TryFrom, TryInto};
 
-use crate::{ParseError, SolveError};
+use crate::log::log_trace;
+use crate::{ParseError, SolveError, Tag};
 
-use crate::day_17::point::Point;
+use crate::point::Point;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Movement {
@@ -18,7 +19,7 @@ impl TryFrom<&str> for Movement {
         match input.trim() {
             "R" => Ok(Movement::Right),
             "L" => Ok(Movement::Left),
-            number => Ok(Movement::Forward(number.parse().unwrap())),
+            number => Ok(Movement::Forward(number.parse()?)),
         }
     }
 }
@@ -87,8 +88,9 @@ pub struct Map {
     height: usize,
 }
 
-#[allow(unused)]
-fn print(map: &Map, person: &Person) {
+fn render(map: &Map, person: &Person) -> String {
+    let mut output = String::new();
+
     for y in 0..map.height {
         for x in 0..map.width {
             let p = Point {
@@ -97,26 +99,26 @@ fn print(map: &Map, person: &Person) {
             };
             let c = if person.position == p {
                 match person.direction {
-                    Direction::Right => ">",
-                    Direction::Down => "v",
-                    Direction::Left => "<",
-                    Direction::Up => "^",
+                    Direction::Right => '>',
+                    Direction::Down => 'v',
+                    Direction::Left => '<',
+                    Direction::Up => '^',
                 }
             } else {
                 match map.get(&p) {
-                    Tile::Open => ".",
-                    Tile::Wall => "#",
-                    Tile::Void => " ",
+                    Tile::Open => '.',
+                    Tile::Wall => '#',
+                    Tile::Void => ' ',
                 }
             };
 
-            print!("{c}");
+            output.push(c);
         }
 
-        println!();
+        output.push('\n');
     }
 
-    println!();
+    output
 }
 
 impl Map {
@@ -271,6 +273,10 @@ impl crate::Solver for Solver {
     type Input = (Map, Vec<Movement>);
     type Output = i64;
     const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Map";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARTS: u8 = 1;
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         let (map, movements) = input.split_once("\n\n").ok_or(ParseError::Invalid)?;
@@ -278,6 +284,17 @@ impl crate::Solver for Solver {
         Ok((parse_map(map)?, parse_movements(movements)?))
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        let (map, movements) = input;
+
+        format!(
+            "{}x{} map, {} movements",
+            map.width,
+            map.height,
+            movements.len()
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         let (map, movements) = input;
 
@@ -290,17 +307,95 @@ impl crate::Solver for Solver {
 
         for movement in movements {
             person.update(&map, &movement);
+
+            log_trace!("After {:?}:\n{}", movement, render(&map, &person));
         }
 
         Ok(person.get_password())
     }
 }
 
+pub struct Walk {
+    map: Map,
+    movements: Vec<Movement>,
+    next_movement: usize,
+    person: Option<Person>,
+}
+
+impl crate::Simulation for Solver {
+    type State = Walk;
+
+    fn start((map, movements): Self::Input) -> Self::State {
+        let person = map.get_starting_point().ok().map(|position| Person {
+            position,
+            direction: Direction::Right,
+        });
+
+        Walk {
+            map,
+            movements,
+            next_movement: 0,
+            person,
+        }
+    }
+
+    fn step(walk: &mut Self::State) -> bool {
+        match (&mut walk.person, walk.movements.get(walk.next_movement)) {
+            (Some(person), Some(movement)) => {
+                person.update(&walk.map, movement);
+                walk.next_movement += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn render(walk: &Self::State) -> String {
+        match &walk.person {
+            Some(person) => render(&walk.map, person),
+            None => write_map(&walk.map),
+        }
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let (map, movements) = input;
+
+        let mut output = write_map(map);
+        output.push('\n');
+        output.extend(movements.iter().map(write_movement));
+        output.push('\n');
+        output
+    }
+}
+
+fn write_map(map: &Map) -> String {
+    (0..map.height)
+        .map(|y| {
+            let row: String = (0..map.width)
+                .map(|x| match map.grid[x + y * map.width] {
+                    Tile::Open => '.',
+                    Tile::Wall => '#',
+                    Tile::Void => ' ',
+                })
+                .collect();
+
+            format!("{}\n", row.trim_end())
+        })
+        .collect()
+}
+
+fn write_movement(movement: &Movement) -> String {
+    match movement {
+        Movement::Left => String::from("L"),
+        Movement::Right => String::from("R"),
+        Movement::Forward(distance) => distance.to_string(),
+    }
+}
+
 fn parse_map(input: &str) -> Result<Map, ParseError> {
-    let rows: Vec<_> = input
-        .lines()
-        .map(|line| parse_row(line))
-        .collect::<Result<_, _>>()?;
+    let rows: Vec<_> = input.lines().map(parse_row).collect::<Result<_, _>>()?;
 
     let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
     let height = rows.len();
@@ -323,10 +418,10 @@ fn parse_map(input: &str) -> Result<Map, ParseError> {
 }
 
 fn parse_row(input: &str) -> Result<Vec<Tile>, ParseError> {
-    Ok(input
+    input
         .chars()
         .map(|c| c.try_into())
-        .collect::<Result<_, _>>()?)
+        .collect::<Result<_, _>>()
 }
 
 fn parse_movements(input: &str) -> Result<Vec<Movement>, ParseError> {
@@ -347,7 +442,8 @@ fn parse_movements(input: &str) -> Result<Vec<Movement>, ParseError> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Solver;
+    use crate::testing::{assert_round_trip, assert_snapshot};
+    use crate::{Simulation, Solver};
 
     use super::*;
 
@@ -471,4 +567,56 @@ mod tests {
 
         assert_eq!(super::Solver::part_1(input).unwrap(), 6032);
     }
+
+    #[test]
+    fn simulation() {
+        let mut walk = super::Solver::start(get_input());
+        let mut steps = 0;
+
+        while super::Solver::step(&mut walk) {
+            steps += 1;
+        }
+
+        let render = super::Solver::render(&walk);
+        let row = render.lines().nth(5).unwrap();
+
+        assert_eq!(steps, 13);
+        assert_eq!(row.chars().nth(7), Some('>'));
+        assert_snapshot("day_22_walk", &render);
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let rows = rng.vec(1..12, |rng| {
+                let mut row = vec![Tile::Void; rng.usize(0..8)];
+                row.extend(rng.vec(1..12, |rng| *rng.choose(&[Tile::Open, Tile::Wall])));
+                row
+            });
+
+            let width = rows.iter().map(|row| row.len()).max().unwrap();
+            let mut map = Map::new(width, rows.len());
+
+            for (y, row) in rows.iter().enumerate() {
+                for (x, tile) in row.iter().enumerate() {
+                    map.set(
+                        &Point {
+                            x: x as i64,
+                            y: y as i64,
+                        },
+                        *tile,
+                    );
+                }
+            }
+
+            let mut movements = vec![Movement::Forward(rng.usize(0..50))];
+
+            for _ in 0..rng.usize(0..10) {
+                movements.push(*rng.choose(&[Movement::Left, Movement::Right]));
+                movements.push(Movement::Forward(rng.usize(0..50)));
+            }
+
+            (map, movements)
+        });
+    }
 }
@@ -1,5 +1,5 @@
-use crate::day_17::point::Point;
-use crate::{ParseError, SolveError};
+use crate::point::Point;
+use crate::{Param, Params, ParseError, SolveError, Tag};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Elf {
@@ -97,18 +97,10 @@ fn move_elves(elves: &mut [Elf], round: usize, strats: &Strategies) -> bool {
     let mut next_positions: Vec<_> = elves
         .iter()
         .map(|elf| {
-            if elf.is_alone(&elves) {
+            if elf.is_alone(elves) {
                 None
-            } else if let Some(pos) = strats[round % strats.len()](elf, &elves) {
-                Some(pos)
-            } else if let Some(pos) = strats[(round + 1) % strats.len()](elf, &elves) {
-                Some(pos)
-            } else if let Some(pos) = strats[(round + 2) % strats.len()](elf, &elves) {
-                Some(pos)
-            } else if let Some(pos) = strats[(round + 3) % strats.len()](elf, &elves) {
-                Some(pos)
             } else {
-                None
+                (0..strats.len()).find_map(|i| strats[(round + i) % strats.len()](elf, elves))
             }
         })
         .collect();
@@ -118,7 +110,7 @@ fn move_elves(elves: &mut [Elf], round: usize, strats: &Strategies) -> bool {
         .map(|pos| next_positions.iter().filter(|&other| other == pos).count() > 1)
         .collect();
 
-    for (pos, is_duplicate) in next_positions.iter_mut().zip(duplicates.into_iter()) {
+    for (pos, is_duplicate) in next_positions.iter_mut().zip(duplicates) {
         if is_duplicate {
             *pos = None;
         }
@@ -126,7 +118,7 @@ fn move_elves(elves: &mut [Elf], round: usize, strats: &Strategies) -> bool {
 
     let any_elf_moves = next_positions.iter().any(|p| p.is_some());
 
-    for (elf, pos) in elves.iter_mut().zip(next_positions.into_iter()) {
+    for (elf, pos) in elves.iter_mut().zip(next_positions) {
         if let Some(pos) = pos {
             elf.cur_pos = pos;
         }
@@ -135,23 +127,23 @@ fn move_elves(elves: &mut [Elf], round: usize, strats: &Strategies) -> bool {
     any_elf_moves
 }
 
-#[allow(unused)]
-fn print(elves: &[Elf]) {
+fn render(elves: &[Elf]) -> String {
     let rect = get_rect(elves);
+    let mut output = String::new();
 
     for y in rect.0.y..=rect.1.y {
         for x in rect.0.x..=rect.1.x {
             if elves.iter().any(|elf| elf.cur_pos == Point { x, y }) {
-                print!("#");
+                output.push('#');
             } else {
-                print!(".");
+                output.push('.');
             }
         }
 
-        println!();
+        output.push('\n');
     }
 
-    println!();
+    output
 }
 
 pub struct Solver {}
@@ -160,20 +152,41 @@ impl crate::Solver for Solver {
     type Input = Vec<Elf>;
     type Output = usize;
     const DAY: u8 = 23;
+    const TITLE: &'static str = "Unstable Diffusion";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARAMS: &'static [Param] = &[Param {
+        name: "rounds",
+        description: "number of rounds in part 1 (10)",
+    }];
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         Ok(input
             .lines()
             .enumerate()
-            .map(|(index, line)| parse_line(line, index))
-            .flatten()
+            .flat_map(|(index, line)| parse_line(line, index))
             .collect())
     }
 
-    fn part_1(mut elves: Self::Input) -> Result<Self::Output, SolveError> {
+    fn summarise(elves: &Self::Input) -> String {
+        let (min, max) = get_rect(elves);
+
+        format!(
+            "{} elves within {}x{}",
+            elves.len(),
+            max.x - min.x + 1,
+            max.y - min.y + 1
+        )
+    }
+
+    fn part_1(elves: Self::Input) -> Result<Self::Output, SolveError> {
+        Self::part_1_with(elves, &Params::new())
+    }
+
+    fn part_1_with(mut elves: Self::Input, params: &Params) -> Result<Self::Output, SolveError> {
         let strats = get_strategies();
 
-        for round in 0..10 {
+        for round in 0..params.get("rounds", 10)? {
             let _ = move_elves(&mut elves, round, &strats);
         }
 
@@ -197,6 +210,53 @@ impl crate::Solver for Solver {
     }
 }
 
+pub struct Grove {
+    elves: Vec<Elf>,
+    round: usize,
+}
+
+impl crate::Simulation for Solver {
+    type State = Grove;
+
+    fn start(elves: Self::Input) -> Self::State {
+        Grove { elves, round: 0 }
+    }
+
+    fn step(grove: &mut Self::State) -> bool {
+        let any_elf_moves = move_elves(&mut grove.elves, grove.round, &get_strategies());
+        grove.round += 1;
+
+        any_elf_moves
+    }
+
+    fn render(grove: &Self::State) -> String {
+        render(&grove.elves)
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let max_x = input.iter().map(|elf| elf.cur_pos.x).max().unwrap_or(0);
+        let max_y = input.iter().map(|elf| elf.cur_pos.y).max().unwrap_or(-1);
+
+        (0..=max_y)
+            .map(|y| {
+                let mut line: String = (0..=max_x)
+                    .map(|x| {
+                        if input.iter().any(|elf| elf.cur_pos == Point { x, y }) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
 fn parse_line(line: &str, index: usize) -> Vec<Elf> {
     line.char_indices()
         .flat_map(|(i, c)| {
@@ -214,7 +274,8 @@ fn parse_line(line: &str, index: usize) -> Vec<Elf> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Solver;
+    use crate::testing::{assert_round_trip, assert_snapshot};
+    use crate::{Simulation, Solver};
 
     use super::*;
 
@@ -270,4 +331,48 @@ mod tests {
     fn part_2() {
         assert_eq!(super::Solver::part_2(get_input()).unwrap(), 20);
     }
+
+    #[test]
+    fn simulation() {
+        let mut grove = super::Solver::start(get_input());
+        let mut rounds = 0;
+
+        while super::Solver::step(&mut grove) {
+            rounds += 1;
+
+            if rounds == 10 {
+                assert_eq!(
+                    super::Solver::render(&grove),
+                    "......#.....\n\
+                     ..........#.\n\
+                     .#.#..#.....\n\
+                     .....#......\n\
+                     ..#.....#..#\n\
+                     #......##...\n\
+                     ....##......\n\
+                     .#........#.\n\
+                     ...#.#..#...\n\
+                     ............\n\
+                     ...#..#..#..\n"
+                );
+            }
+        }
+
+        assert_eq!(rounds + 1, 20);
+        assert_snapshot("day_23_grove", &super::Solver::render(&grove));
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let width = rng.i64(1..15);
+            let height = rng.i64(1..15);
+
+            (0..height)
+                .flat_map(|y| (0..width).map(move |x| Point { x, y }))
+                .filter(|_| rng.bool())
+                .map(Elf::new)
+                .collect()
+        });
+    }
 }
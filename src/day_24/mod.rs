@@ -3,8 +3,8 @@ use std::convert::{TryFrom, TryInto};
 use std::fmt::{self, Display, Formatter};
 use std::ops::Add;
 
-use crate::day_17::point::Point;
-use crate::{ParseError, SolveError};
+use crate::point::Point;
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Grid {
@@ -179,13 +179,13 @@ impl Grid {
 
 impl Display for Grid {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        let _ = writeln!(f, "Grid {{")?;
+        writeln!(f, "Grid {{")?;
 
         for y in 0..self.height {
-            let _ = write!(f, "    ")?;
+            write!(f, "    ")?;
 
             for x in 0..self.width {
-                let _ = match self.get_num_blizzards(x, y) {
+                match self.get_num_blizzards(x, y) {
                     0 => write!(f, "."),
                     1 => match self.get(x, y).try_into() {
                         Ok(Direction::North) => write!(f, "^"),
@@ -198,10 +198,10 @@ impl Display for Grid {
                 }?;
             }
 
-            let _ = writeln!(f)?;
+            writeln!(f)?;
         }
 
-        let _ = writeln!(f, "}}")?;
+        writeln!(f, "}}")?;
 
         Ok(())
     }
@@ -213,17 +213,28 @@ impl crate::Solver for Solver {
     type Input = (Grid, Point);
     type Output = usize;
     const DAY: u8 = 24;
+    const TITLE: &'static str = "Blizzard Basin";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Search, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         let lines: Vec<_> = input.lines().collect();
         let num_lines = lines.len();
 
+        if num_lines < 3 {
+            return Err(ParseError::Incomplete);
+        }
+
         let expedition = Point::new(
             parse_border(lines.first().ok_or(ParseError::Invalid)?)? as i64,
             0,
         );
 
-        let width = lines.first().ok_or(ParseError::Invalid)?.chars().count();
+        let width = lines.first().ok_or(ParseError::Invalid)?.len();
+
+        if width < 3 || lines.iter().any(|line| line.len() != width) {
+            return Err(ParseError::Invalid);
+        }
         let height = num_lines;
         let destination = parse_border(lines.last().ok_or(ParseError::Invalid)?)?;
         let mut grid = Grid::new(width, height);
@@ -237,6 +248,20 @@ impl crate::Solver for Solver {
         Ok((grid, expedition))
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        let (grid, _) = input;
+
+        format!(
+            "{}x{} valley with {} blizzards",
+            grid.width,
+            grid.height,
+            grid.blizzards
+                .iter()
+                .map(|blizzards| blizzards.count_ones())
+                .sum::<u32>()
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         let (grid, start) = input;
 
@@ -262,13 +287,114 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let (grid, _) = input;
+
+        (0..grid.height)
+            .map(|y| {
+                let mut line: String = (0..grid.width).map(|x| write_tile(grid, x, y)).collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+pub struct Valley {
+    grid: Grid,
+    expeditions: HashSet<Expedition>,
+}
+
+impl crate::Simulation for Solver {
+    type State = Valley;
+
+    fn start(input: Self::Input) -> Self::State {
+        let (grid, start) = input;
+
+        let mut expeditions = HashSet::new();
+        expeditions.insert(Expedition {
+            status: Status::WithSnacks,
+            pos: start,
+        });
+
+        Valley { grid, expeditions }
+    }
+
+    fn step(valley: &mut Self::State) -> bool {
+        if valley.expeditions.is_empty()
+            || valley
+                .expeditions
+                .iter()
+                .any(|expedition| expedition.pos == valley.grid.destination)
+        {
+            return false;
+        }
+
+        let expeditions = std::mem::take(&mut valley.expeditions);
+        let (grid, expeditions) = advance(&valley.grid, expeditions);
+        valley.grid = grid;
+        valley.expeditions = expeditions;
+
+        true
+    }
+
+    fn render(valley: &Self::State) -> String {
+        let grid = &valley.grid;
+
+        (0..grid.height)
+            .map(|y| {
+                let mut line: String = (0..grid.width)
+                    .map(|x| {
+                        let point = Point::new(x as i64, y as i64);
+
+                        if valley.expeditions.iter().any(|e| e.pos == point) {
+                            'E'
+                        } else {
+                            match grid.get_num_blizzards(x, y) {
+                                0 | 1 => write_tile(grid, x, y),
+                                n => char::from_digit(n, 10).unwrap_or('*'),
+                            }
+                        }
+                    })
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+fn write_tile(grid: &Grid, x: usize, y: usize) -> char {
+    let point = Point::new(x as i64, y as i64);
+
+    if point == grid.start || point == grid.destination {
+        '.'
+    } else if x == 0 || y == 0 || x + 1 == grid.width || y + 1 == grid.height {
+        '#'
+    } else {
+        match grid.get(x, y).try_into() {
+            Ok(Direction::North) => '^',
+            Ok(Direction::East) => '>',
+            Ok(Direction::South) => 'v',
+            Ok(Direction::West) => '<',
+            Err(_) => '.',
+        }
+    }
+}
+
 fn parse_line(grid: &mut Grid, line: &str, y: usize) -> Result<(), ParseError> {
-    for (i, c) in line.char_indices() {
-        if c == '.' || c == '#' {
+    let inside = line
+        .strip_prefix('#')
+        .and_then(|line| line.strip_suffix('#'))
+        .ok_or(ParseError::Invalid)?;
+
+    for (i, c) in inside.char_indices() {
+        if c == '.' {
             continue;
         }
 
-        grid.add_blizzard(i, y, c.try_into()?);
+        grid.add_blizzard(i + 1, y, c.try_into()?);
     }
 
     Ok(())
@@ -291,44 +417,60 @@ fn get_neighbors(expedition: Expedition) -> [Expedition; 5] {
     ]
 }
 
-fn get_fastest_path(grid: Grid, expeditions: HashSet<Expedition>) -> Option<usize> {
-    if expeditions.is_empty() {
-        None
-    } else if expeditions.iter().any(|expedition| {
-        expedition.status == Status::WithSnacks && expedition.pos == grid.destination
-    }) {
-        Some(0)
-    } else {
-        let grid = grid.updated();
+fn get_fastest_path(mut grid: Grid, mut expeditions: HashSet<Expedition>) -> Option<usize> {
+    // The blizzards repeat after (width - 2) * (height - 2) minutes, so the
+    // search has at most that many times as many states as there are
+    // expeditions. A destination that is not reached by then never is.
+    let limit = (grid.width - 2) * (grid.height - 2) * grid.width * grid.height * 3;
+
+    for minutes in 0..=limit {
+        if expeditions.is_empty() {
+            return None;
+        } else if expeditions.iter().any(|expedition| {
+            expedition.status == Status::WithSnacks && expedition.pos == grid.destination
+        }) {
+            return Some(minutes);
+        }
 
-        let expeditions = expeditions
-            .into_iter()
-            .flat_map(|expedition| get_neighbors(expedition))
-            .filter(|expedition| {
-                grid.is_valid(&expedition.pos) && !grid.has_any_blizzard(&expedition.pos)
-            })
-            .map(|mut expedition| {
-                let Expedition { pos, status } = expedition;
+        let (new_grid, new_expeditions) = advance(&grid, expeditions);
+        grid = new_grid;
+        expeditions = new_expeditions;
+    }
 
-                if matches!(status, Status::WithoutSnacks) && pos == grid.destination {
-                    expedition.status = Status::GettingSnacks;
-                } else if matches!(status, Status::GettingSnacks) && pos == grid.start {
-                    expedition.status = Status::WithSnacks;
-                }
+    None
+}
 
-                expedition
-            })
-            .collect();
+fn advance(grid: &Grid, expeditions: HashSet<Expedition>) -> (Grid, HashSet<Expedition>) {
+    let grid = grid.updated();
+
+    let expeditions = expeditions
+        .into_iter()
+        .flat_map(get_neighbors)
+        .filter(|expedition| {
+            grid.is_valid(&expedition.pos) && !grid.has_any_blizzard(&expedition.pos)
+        })
+        .map(|mut expedition| {
+            let Expedition { pos, status } = expedition;
+
+            if matches!(status, Status::WithoutSnacks) && pos == grid.destination {
+                expedition.status = Status::GettingSnacks;
+            } else if matches!(status, Status::GettingSnacks) && pos == grid.start {
+                expedition.status = Status::WithSnacks;
+            }
 
-        get_fastest_path(grid, expeditions).map(|path| path + 1)
-    }
+            expedition
+        })
+        .collect();
+
+    (grid, expeditions)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::Solver;
+    use crate::testing::{assert_round_trip, assert_snapshot};
+    use crate::{Simulation, Solver};
 
     fn get_input() -> (Grid, Point) {
         let mut grid = Grid::new(8, 6);
@@ -375,6 +517,17 @@ mod tests {
             super::Solver::parse(String::from(input)).unwrap(),
             get_input(),
         );
+
+        for input in ["#.#\n#.#", "#.##\n#..\n##.#", "#.##\n#.x#\n##.#"] {
+            assert!(super::Solver::parse(String::from(input)).is_err());
+        }
+    }
+
+    #[test]
+    fn unreachable() {
+        let input = super::Solver::parse(String::from("#.#\n#v#\n#.#"));
+
+        assert!(super::Solver::part_1(input.unwrap()).is_err());
     }
 
     #[test]
@@ -429,4 +582,60 @@ mod tests {
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 54);
     }
+
+    #[test]
+    fn simulation() {
+        let mut valley = super::Solver::start(get_input());
+
+        assert!(super::Solver::step(&mut valley));
+        assert_eq!(
+            super::Solver::render(&valley),
+            "#E######\n\
+             #E>3.<.#\n\
+             #<..<<.#\n\
+             #>2.22.#\n\
+             #>v..^<#\n\
+             ######.#\n"
+        );
+
+        let mut minutes = 1;
+
+        while super::Solver::step(&mut valley) {
+            minutes += 1;
+        }
+
+        assert_eq!(minutes, 18);
+        assert_snapshot("day_24_valley", &super::Solver::render(&valley));
+    }
+
+    #[test]
+    fn round_trip() {
+        let directions = [
+            Direction::North as u8,
+            Direction::East as u8,
+            Direction::South as u8,
+            Direction::West as u8,
+        ];
+
+        assert_round_trip::<super::Solver>(|rng| {
+            let width = rng.usize(3..12);
+            let height = rng.usize(3..10);
+
+            let mut grid = Grid::new(width, height);
+            grid.start = Point::new(rng.usize(1..width - 1) as i64, 0);
+            grid.destination = Point::new(rng.usize(1..width - 1) as i64, height as i64 - 1);
+
+            for y in 1..height - 1 {
+                for x in 1..width - 1 {
+                    if rng.bool() {
+                        let direction = *rng.choose(&directions);
+                        grid.add_blizzard(x, y, direction.try_into().unwrap());
+                    }
+                }
+            }
+
+            let start = grid.start;
+            (grid, start)
+        });
+    }
 }
@@ -1,6 +1,6 @@
-use std::iter::FromIterator;
-
-use crate::{ParseError, SolveError};
+use crate::arithmetic;
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
 fn parse(c: char) -> Result<i64, SolveError> {
     match c {
@@ -25,42 +25,35 @@ fn to_char(snafu: i64) -> char {
 }
 
 fn from_snafu(snafu: &str) -> Result<i64, SolveError> {
-    let chars: Vec<_> = snafu.chars().map(|c| parse(c)).collect::<Result<_, _>>()?;
+    let chars: Vec<_> = snafu.chars().map(parse).collect::<Result<_, _>>()?;
 
-    Ok(chars
+    chars
         .into_iter()
         .rev()
         .enumerate()
-        .fold(0, |acc, (i, snafu)| acc + 5i64.pow(i as u32) * snafu))
+        .try_fold(0, |acc, (i, snafu)| {
+            let place = arithmetic::pow(5i64, i as u32, "5 to the power of a digit position")?;
+            let value = arithmetic::mul(place, snafu, "digit * 5 to the power of its position")?;
+
+            arithmetic::add(acc, value, "sum of digits")
+        })
 }
 
 fn to_snafu(mut number: i64) -> String {
-    let highest_power = (0..).find(|&i| 5i64.pow(i as u32) > number).unwrap() + 1;
+    if number == 0 {
+        return String::from("0");
+    }
 
-    let mut factors: Vec<_> = (0..highest_power)
-        .rev()
-        .map(|i| {
-            let factor = (0..5)
-                .filter(|factor| factor * 5i64.pow(i as u32) <= number)
-                .max()
-                .unwrap();
-            number -= factor * 5i64.pow(i as u32);
-            factor
-        })
-        .collect();
-
-    for i in (0..factors.len()).rev() {
-        match factors[i] {
-            f @ 3..=5 => {
-                factors[i - 1] += 1;
-                factors[i] = f - 5;
-            }
-            _ => (),
-        }
+    let mut digits = vec![];
+
+    // Each digit is the remainder in -2..=2, which also handles negative sums.
+    while number != 0 {
+        let digit = (number + 2).rem_euclid(5) - 2;
+        digits.push(to_char(digit));
+        number = (number - digit) / 5;
     }
 
-    let start_index = factors.iter().take_while(|&f| *f == 0).count();
-    String::from_iter(factors[start_index..].iter().map(|f| to_char(*f)))
+    digits.into_iter().rev().collect()
 }
 
 pub struct Solver {}
@@ -69,25 +62,51 @@ impl crate::Solver for Solver {
     type Input = Vec<String>;
     type Output = String;
     const DAY: u8 = 25;
+    const TITLE: &'static str = "Full of Hot Air";
+    const TAGS: &'static [Tag] = &[Tag::Math];
+    const EXAMPLE: &'static str = include_str!("example.txt");
+    const PARTS: u8 = 1;
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         Ok(input.lines().map(|line| line.to_owned()).collect())
     }
 
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        Ok(reader.lines().collect::<Result<_, _>>()?)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{} SNAFU numbers of up to {} digits",
+            input.len(),
+            input.iter().map(|snafu| snafu.len()).max().unwrap_or(0)
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         let numbers = input
             .iter()
             .map(|snafu| from_snafu(snafu))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let sum = numbers.into_iter().sum();
+        let sum = numbers.into_iter().try_fold(0, |acc, number| {
+            arithmetic::add(acc, number, "sum of numbers")
+        })?;
         Ok(to_snafu(sum))
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input.iter().map(|snafu| format!("{snafu}\n")).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::solving::Solver;
+    use crate::testing::assert_round_trip;
+    use crate::SolveError;
 
     const SNAFU_TO_NUMBER: [(&str, i64); 13] = [
         ("1=-0-2", 1747),
@@ -124,6 +143,9 @@ mod tests {
         for (snafu, number) in SNAFU_TO_NUMBER {
             assert_eq!(&super::to_snafu(number), snafu);
         }
+
+        assert_eq!(super::to_snafu(0), "0");
+        assert_eq!(super::to_snafu(-3), "-2");
     }
 
     #[test]
@@ -157,4 +179,26 @@ mod tests {
             String::from("2=-1=0")
         );
     }
+
+    #[test]
+    fn overflow() {
+        let input = vec![format!("1{}", "0".repeat(30))];
+        let error = super::Solver::part_1(input).unwrap_err();
+
+        if cfg!(feature = "checked") {
+            assert_eq!(
+                error.to_string(),
+                "Overflow in 5 to the power of a digit position"
+            );
+        } else {
+            assert!(matches!(error, SolveError::InvalidInput));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..20, |rng| super::to_snafu(rng.i64(1..1_000_000)))
+        });
+    }
 }
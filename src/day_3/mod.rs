@@ -1,5 +1,7 @@
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
+#[derive(Debug, PartialEq)]
 pub struct Rucksack(u64, u64);
 
 pub type Input = Vec<Rucksack>;
@@ -14,18 +16,34 @@ impl Rucksack {
         }
     }
 
-    pub fn find_duplicate(&self) -> char {
+    pub fn find_duplicate(&self) -> Option<char> {
         from_u64(self.0 & self.1)
     }
+
+    fn write(&self) -> String {
+        let mut compartment_1 = to_chars(self.0);
+        let mut compartment_2 = to_chars(self.1);
+
+        if let Some(&c) = compartment_1.first() {
+            compartment_1.resize(compartment_1.len().max(compartment_2.len()), c);
+        }
+
+        if let Some(&c) = compartment_2.first() {
+            compartment_2.resize(compartment_2.len().max(compartment_1.len()), c);
+        }
+
+        compartment_1.into_iter().chain(compartment_2).collect()
+    }
 }
 
 fn input_is_valid(input: &str) -> bool {
-    input
-        .chars()
-        .all(|c| c.is_ascii_lowercase() || c.is_ascii_uppercase())
+    input.len().is_multiple_of(2)
+        && input
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_uppercase())
 }
 
-fn find_badge(first: &Rucksack, second: &Rucksack, third: &Rucksack) -> char {
+fn find_badge(first: &Rucksack, second: &Rucksack, third: &Rucksack) -> Option<char> {
     from_u64((first.0 | first.1) & (second.0 | second.1) & (third.0 | third.1))
 }
 
@@ -33,16 +51,23 @@ fn to_u64(input: &str) -> u64 {
     input.chars().fold(0, |acc, c| acc | 1 << to_priority(c))
 }
 
-fn from_u64(input: u64) -> char {
+fn from_u64(input: u64) -> Option<char> {
     let priority = input.trailing_zeros() as u8;
 
     match priority {
-        1..=26 => (priority - 1 + 'a' as u8) as char,
-        27..=52 => (priority - 27 + 'A' as u8) as char,
-        _ => unreachable!(),
+        1..=26 => Some((priority - 1 + b'a') as char),
+        27..=52 => Some((priority - 27 + b'A') as char),
+        _ => None,
     }
 }
 
+fn to_chars(input: u64) -> Vec<char> {
+    (1..=52)
+        .filter(|priority| input & 1 << priority != 0)
+        .filter_map(|priority| from_u64(1 << priority))
+        .collect()
+}
+
 fn to_priority(c: char) -> u64 {
     if c.is_ascii_lowercase() {
         (c as u64) - ('a' as u64) + 1
@@ -59,32 +84,59 @@ impl crate::Solver for Solver {
     type Input = Input;
     type Output = u64;
     const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        Ok(input
-            .lines()
-            .map(|line| Rucksack::new(line))
-            .collect::<Result<_, _>>()?)
+        input.lines().map(Rucksack::new).collect::<Result<_, _>>()
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        reader.lines().map(|line| Rucksack::new(&line?)).collect()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!("{} rucksacks in {} groups", input.len(), input.len() / 3)
     }
 
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
-        Ok(input
+        input
             .iter()
-            .map(|rucksack| to_priority(rucksack.find_duplicate()))
-            .sum())
+            .map(|rucksack| {
+                rucksack
+                    .find_duplicate()
+                    .map(to_priority)
+                    .ok_or(SolveError::InvalidInput)
+            })
+            .sum()
     }
 
     fn part_2(input: Self::Input) -> Result<Self::Output, SolveError> {
-        Ok(input
+        input
             .chunks_exact(3)
-            .map(|chunk| to_priority(find_badge(&chunk[0], &chunk[1], &chunk[2])))
-            .sum())
+            .map(|chunk| {
+                find_badge(&chunk[0], &chunk[1], &chunk[2])
+                    .map(to_priority)
+                    .ok_or(SolveError::InvalidInput)
+            })
+            .sum()
+    }
+}
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|rucksack| format!("{}\n", rucksack.write()))
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::solving::Solver;
+    use crate::testing::assert_round_trip;
 
     use super::*;
 
@@ -101,7 +153,11 @@ mod tests {
     #[test]
     fn find_duplicate() {
         let rucksack = Rucksack::new("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
-        assert_eq!(rucksack.find_duplicate(), 'p');
+        assert_eq!(rucksack.find_duplicate(), Some('p'));
+
+        let rucksack = Rucksack::new("abcd").unwrap();
+        assert_eq!(rucksack.find_duplicate(), None);
+        assert!(Rucksack::new("abc").is_err());
     }
 
     fn get_rucksacks() -> Vec<Rucksack> {
@@ -126,4 +182,20 @@ mod tests {
         let rucksacks = get_rucksacks();
         assert_eq!(super::Solver::part_2(rucksacks).unwrap(), 70);
     }
+
+    #[test]
+    fn round_trip() {
+        let items: Vec<_> = ('a'..='z').chain('A'..='Z').collect();
+
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..20, |rng| {
+                let half: String = rng
+                    .vec(1..15, |rng| *rng.choose(&items))
+                    .into_iter()
+                    .collect();
+                let other: String = half.chars().map(|_| *rng.choose(&items)).collect();
+                Rucksack::new(&(half + &other)).unwrap()
+            })
+        });
+    }
 }
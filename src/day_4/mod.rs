@@ -1,4 +1,5 @@
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
 pub struct Solver {}
 
@@ -8,16 +9,33 @@ impl crate::Solver for Solver {
     type Input = Vec<(Range, Range)>;
     type Output = u64;
     const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        let pairs = input
-            .lines()
-            .map(|line| parse_line(line))
-            .collect::<Result<_, _>>()?;
+        let pairs = input.lines().map(parse_line).collect::<Result<_, _>>()?;
 
         Ok(pairs)
     }
 
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        reader.lines().map(|line| parse_line(&line?)).collect()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        let sections = input
+            .iter()
+            .flat_map(|(a, b)| [a.start(), a.end(), b.start(), b.end()]);
+
+        format!(
+            "{} pairs covering sections {} to {}",
+            input.len(),
+            sections.clone().min().unwrap_or(&0),
+            sections.max().unwrap_or(&0)
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         Ok(input
             .iter()
@@ -33,8 +51,17 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|(a, b)| format!("{}-{},{}-{}\n", a.start(), a.end(), b.start(), b.end()))
+            .collect()
+    }
+}
+
 fn parse_line(line: &str) -> Result<(Range, Range), ParseError> {
-    let mut splits = line.split(',').map(|range| parse_range(range));
+    let mut splits = line.split(',').map(parse_range);
 
     match (splits.next(), splits.next()) {
         (Some(first), Some(second)) => Ok((first?, second?)),
@@ -61,6 +88,7 @@ fn range_overlaps(a: &Range, b: &Range) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     fn get_input() -> <super::Solver as crate::Solver>::Input {
@@ -98,4 +126,15 @@ mod tests {
         let input = get_input();
         assert_eq!(super::Solver::part_2(input).unwrap(), 4);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..20, |rng| {
+                let a = rng.next_u64() % 100;
+                let b = rng.next_u64() % 100;
+                (a..=a + rng.next_u64() % 10, b..=b + rng.next_u64() % 10)
+            })
+        });
+    }
 }
@@ -1,6 +1,6 @@
 mod parsing;
 
-use crate::{ParseError, SolveError};
+use crate::{ParseError, SolveError, Tag};
 
 pub type Crates = Vec<Vec<char>>;
 
@@ -17,20 +17,53 @@ impl crate::Solver for Solver {
     type Input = (Crates, Vec<Task>);
     type Output = String;
     const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+    const TAGS: &'static [Tag] = &[Tag::Parsing, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         parsing::parse(input)
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        let (crates, tasks) = input;
+
+        format!(
+            "{} stacks holding {} crates, {} moves",
+            crates.len(),
+            crates.iter().map(|stack| stack.len()).sum::<usize>(),
+            tasks.len()
+        )
+    }
+
+    fn validate(input: &Self::Input) -> Vec<String> {
+        let (crates, tasks) = input;
+        let mut problems = vec![];
+
+        for (i, task) in tasks.iter().enumerate() {
+            for stack in [task.from, task.to].iter() {
+                if *stack == 0 || *stack > crates.len() {
+                    problems.push(format!(
+                        "Move {} uses stack {}, which does not exist",
+                        i + 1,
+                        stack
+                    ));
+                }
+            }
+        }
+
+        problems
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         let (mut crates, tasks) = input;
 
         for task in tasks {
             for _ in 0..task.num {
-                let top = crates[task.from - 1]
+                let top = stack(&mut crates, task.from)?
                     .pop()
                     .ok_or(SolveError::InvalidInput)?;
-                crates[task.to - 1].push(top);
+                stack(&mut crates, task.to)?.push(top);
             }
         }
 
@@ -41,15 +74,33 @@ impl crate::Solver for Solver {
         let (mut crates, tasks) = input;
 
         for task in tasks {
-            let len = crates[task.from - 1].len();
-            let moving_crates = crates[task.from - 1].split_off(len - task.num);
-            crates[task.to - 1].extend(moving_crates.into_iter());
+            let from = stack(&mut crates, task.from)?;
+            let at = from
+                .len()
+                .checked_sub(task.num)
+                .ok_or(SolveError::InvalidInput)?;
+            let moving_crates = from.split_off(at);
+            stack(&mut crates, task.to)?.extend(moving_crates);
         }
 
         Ok(to_string(&crates))
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        parsing::write(input)
+    }
+}
+
+// Stacks are numbered from 1.
+fn stack(crates: &mut Crates, number: usize) -> Result<&mut Vec<char>, SolveError> {
+    number
+        .checked_sub(1)
+        .and_then(move |index| crates.get_mut(index))
+        .ok_or(SolveError::InvalidInput)
+}
+
 fn to_string(crates: &Crates) -> String {
     crates
         .iter()
@@ -60,7 +111,8 @@ fn to_string(crates: &Crates) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Solver;
+    use crate::testing::assert_snapshot;
+    use crate::{Solver, Writer};
 
     pub fn get_input() -> (Crates, Vec<Task>) {
         let crates = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
@@ -104,4 +156,33 @@ mod tests {
 
         assert_eq!(result, "MCD");
     }
+
+    #[test]
+    fn rendering() {
+        assert_snapshot("day_5_input", &super::Solver::write(&get_input()));
+    }
+
+    #[test]
+    fn validate() {
+        assert!(super::Solver::validate(&get_input()).is_empty());
+
+        let (crates, mut tasks) = get_input();
+        tasks[2].to = 4;
+
+        assert_eq!(
+            super::Solver::validate(&(crates, tasks)),
+            vec!["Move 3 uses stack 4, which does not exist"]
+        );
+    }
+
+    #[test]
+    fn missing_stacks() {
+        let (crates, mut tasks) = get_input();
+        tasks[0].from = 0;
+        assert!(super::Solver::part_1((crates.clone(), tasks)).is_err());
+
+        let (_, mut tasks) = get_input();
+        tasks[1].num = 4;
+        assert!(super::Solver::part_2((crates, tasks)).is_err());
+    }
 }
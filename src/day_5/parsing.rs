@@ -9,6 +9,37 @@ pub fn parse(input: String) -> Result<(Crates, Vec<Task>), ParseError> {
     Ok((crates, tasks))
 }
 
+pub fn write((crates, tasks): &(Crates, Vec<Task>)) -> String {
+    let height = crates.iter().map(|stack| stack.len()).max().unwrap_or(0);
+
+    let crate_lines = (0..height).rev().map(|level| {
+        crates
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => String::from("   "),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+
+    let number_line = (1..=crates.len())
+        .map(|number| format!(" {number} "))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let task_lines = tasks
+        .iter()
+        .map(|task| format!("move {} from {} to {}", task.num, task.from, task.to));
+
+    crate_lines
+        .chain(std::iter::once(number_line))
+        .map(|line| format!("{}\n", line.trim_end()))
+        .chain(std::iter::once(String::from("\n")))
+        .chain(task_lines.map(|line| format!("{line}\n")))
+        .collect()
+}
+
 fn parse_crates(input: &str) -> Crates {
     let crate_lines: Vec<_> = input
         .lines()
@@ -42,11 +73,11 @@ fn add_crate(crates: &mut Crates, index: usize, character: char) {
 }
 
 fn parse_tasks(input: &str) -> Result<Vec<Task>, ParseError> {
-    Ok(input
+    input
         .lines()
         .skip_while(|line| !line.trim_start().starts_with("move"))
-        .map(|line| parse_task(line))
-        .collect::<Result<_, _>>()?)
+        .map(parse_task)
+        .collect::<Result<_, _>>()
 }
 
 fn parse_task(line: &str) -> Result<Task, ParseError> {
@@ -62,12 +93,14 @@ fn parse_task(line: &str) -> Result<Task, ParseError> {
 fn parse_as_usize(value: Option<&str>) -> Result<usize, ParseError> {
     value
         .ok_or(ParseError::Invalid)
-        .and_then(|value| value.parse().map_err(|e| ParseError::from(e)))
+        .and_then(|value| value.parse().map_err(ParseError::from))
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::tests::get_input;
+    use super::super::Task;
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     #[test]
@@ -88,4 +121,40 @@ move 1 from 1 to 2";
         assert_eq!(crates, ref_crates);
         assert_eq!(tasks, ref_tasks);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::super::Solver>(|rng| {
+            let num_stacks = rng.usize(1..12);
+
+            let mut crates = rng.vec(num_stacks..num_stacks + 1, |rng| {
+                rng.vec(0..8, |rng| (b'A' + rng.usize(0..26) as u8) as char)
+            });
+            crates[num_stacks - 1].push('Z');
+
+            let tasks = rng.vec(0..10, |rng| Task {
+                num: rng.usize(1..10),
+                from: rng.usize(1..num_stacks + 1),
+                to: rng.usize(1..num_stacks + 1),
+            });
+
+            (crates, tasks)
+        });
+    }
+
+    #[test]
+    fn writing() {
+        let input = r"    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+        assert_eq!(super::write(&get_input()), input);
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
 fn get_index(input: Vec<char>, window_size: usize) -> Result<usize, SolveError> {
     input
@@ -27,11 +28,30 @@ impl crate::Solver for Solver {
     type Input = Vec<char>;
     type Output = usize;
     const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
         Ok(input.chars().collect())
     }
 
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input, ParseError> {
+        let mut chars = vec![];
+        let mut line = String::new();
+
+        while reader.read_line(&mut line)? > 0 {
+            chars.extend(line.chars());
+            line.clear();
+        }
+
+        Ok(chars)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!("{} characters", input.len())
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         get_index(input, 4)
     }
@@ -40,8 +60,15 @@ impl crate::Solver for Solver {
         get_index(input, 14)
     }
 }
+
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input.iter().collect()
+    }
+}
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     #[test]
@@ -105,4 +132,11 @@ mod tests {
         let input = super::Solver::parse(String::from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")).unwrap();
         assert_eq!(super::Solver::part_2(input).unwrap(), 26);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..100, |rng| (b'a' + rng.usize(0..26) as u8) as char)
+        });
+    }
 }
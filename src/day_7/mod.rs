@@ -1,4 +1,5 @@
-use crate::{ParseError, SolveError};
+use crate::arithmetic;
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(Debug, PartialEq)]
 pub struct Dir {
@@ -29,11 +30,37 @@ impl Dir {
         self.size += size;
     }
 
+    fn write(&self, output: &mut String) {
+        output.push_str(&format!("$ cd {}\n$ ls\n", self.name));
+
+        for child in self.children.iter() {
+            output.push_str(&format!("dir {}\n", child.name));
+        }
+
+        for (index, size) in self.files.iter().enumerate() {
+            output.push_str(&format!("{size} {index}.txt\n"));
+        }
+
+        for child in self.children.iter() {
+            child.write(output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+
+    fn count(&self) -> (usize, usize) {
+        self.children
+            .iter()
+            .fold((1, self.files.len()), |(dirs, files), child| {
+                let (child_dirs, child_files) = child.count();
+                (dirs + child_dirs, files + child_files)
+            })
+    }
+
     fn get_dir_sizes(&self) -> Vec<usize> {
         self.children
             .iter()
             .fold(vec![self.size], |mut acc, child| {
-                acc.extend(child.get_dir_sizes().into_iter());
+                acc.extend(child.get_dir_sizes());
                 acc
             })
     }
@@ -52,9 +79,12 @@ impl crate::Solver for Solver {
     type Input = Dir;
     type Output = usize;
     const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Parsing];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        let mut commands = input.lines().map(|line| parse_line(line));
+        let mut commands = input.lines().map(parse_line);
 
         if let Command::Cd(root) = commands.next().ok_or(ParseError::Invalid)?? {
             explore_dir(root, &mut commands)
@@ -63,6 +93,15 @@ impl crate::Solver for Solver {
         }
     }
 
+    fn summarise(input: &Self::Input) -> String {
+        let (dirs, files) = input.count();
+
+        format!(
+            "{} directories, {} files, {} bytes in total",
+            dirs, files, input.size
+        )
+    }
+
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
         Ok(input
             .get_dir_sizes()
@@ -75,8 +114,9 @@ impl crate::Solver for Solver {
         const TOTAL_SPACE: usize = 70000000;
         const REQUIRED_SPACE: usize = 30000000;
         let used_space = input.size;
-        let unused_space = TOTAL_SPACE - used_space;
-        let needed_space = REQUIRED_SPACE - unused_space;
+        let unused_space = arithmetic::sub(TOTAL_SPACE, used_space, "total - used space")?;
+        let needed_space =
+            arithmetic::sub(REQUIRED_SPACE, unused_space, "required - unused space")?;
 
         input
             .get_dir_sizes()
@@ -87,19 +127,24 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        let mut output = String::new();
+        input.write(&mut output);
+        output
+    }
+}
+
 fn parse_line(line: &str) -> Result<Command, ParseError> {
     if line.starts_with("$ cd ..") {
         Ok(Command::Up)
-    } else if line.starts_with("$ cd") {
-        Ok(Command::Cd(line[5..].to_owned()))
-    } else if line.starts_with("$ ls") {
-        Ok(Command::Skip)
-    } else if line.starts_with("dir") {
+    } else if let Some(name) = line.strip_prefix("$ cd ") {
+        Ok(Command::Cd(name.to_owned()))
+    } else if line.starts_with("$ ls") || line.starts_with("dir") {
         Ok(Command::Skip)
-    } else if let Ok(size) = line[..line.find(" ").ok_or(ParseError::Invalid)?].parse() {
-        Ok(Command::AddFile(size))
     } else {
-        Err(ParseError::Invalid)
+        let (size, _) = line.split_once(' ').ok_or(ParseError::Invalid)?;
+        Ok(Command::AddFile(size.parse()?))
     }
 }
 
@@ -124,6 +169,7 @@ fn explore_dir(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{assert_round_trip, Rng};
     use crate::Solver;
 
     fn get_input() -> Dir {
@@ -152,6 +198,23 @@ mod tests {
         root
     }
 
+    fn generate_dir(rng: &mut Rng, name: String, depth: usize) -> Dir {
+        let mut dir = Dir::new(name);
+
+        for _ in 0..rng.usize(0..5) {
+            dir.add_file(rng.usize(1..300_000));
+        }
+
+        if depth > 0 {
+            for _ in 0..rng.usize(0..4) {
+                let name = rng.name(1..6);
+                dir.add_dir(generate_dir(rng, name, depth - 1));
+            }
+        }
+
+        dir
+    }
+
     #[test]
     fn parsing() {
         let input = r"$ cd /
@@ -182,6 +245,9 @@ $ ls
             super::Solver::parse(String::from(input)).unwrap(),
             get_input()
         );
+
+        assert!(super::Solver::parse(String::from("$ cd")).is_err());
+        assert!(super::Solver::parse(String::from("$ cd /\n123")).is_err());
     }
 
     #[test]
@@ -193,4 +259,9 @@ $ ls
     fn part_2() {
         assert_eq!(super::Solver::part_2(get_input()).unwrap(), 24933642);
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| generate_dir(rng, String::from("/"), 3));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{ParseError, SolveError};
+use crate::{ParseError, SolveError, Tag};
 
 pub struct Solver {}
 
@@ -6,13 +6,34 @@ impl crate::Solver for Solver {
     type Input = Vec<Vec<u8>>;
     type Output = usize;
     const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+    const TAGS: &'static [Tag] = &[Tag::Grid];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        input.lines().map(|line| parse_line(line)).collect()
+        let trees: Vec<_> = input.lines().map(parse_line).collect::<Result<_, _>>()?;
+
+        if trees.iter().any(|row| row.len() != trees[0].len()) {
+            return Err(ParseError::Invalid);
+        }
+
+        Ok(trees)
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        let heights = input.iter().flat_map(|row| row.iter());
+
+        format!(
+            "{}x{} trees with heights {} to {}",
+            input.first().map(|row| row.len()).unwrap_or(0),
+            input.len(),
+            heights.clone().min().unwrap_or(&0),
+            heights.max().unwrap_or(&0)
+        )
     }
 
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
-        Ok((0..input[0].len())
+        Ok((0..input.first().map_or(0, |row| row.len()))
             .map(|col| {
                 (0..input.len())
                     .filter(|row| is_visible(&input, col, *row))
@@ -36,19 +57,32 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|row| {
+                let mut line: String = row.iter().map(|height| height.to_string()).collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
 fn parse_line(line: &str) -> Result<Vec<u8>, ParseError> {
     line.chars()
         .map(|c| c.to_digit(10).map(|d| d as u8).ok_or(ParseError::Invalid))
         .collect::<Result<Vec<_>, _>>()
 }
 
-fn is_visible(trees: &Vec<Vec<u8>>, i: usize, j: usize) -> bool {
+fn is_visible(trees: &[Vec<u8>], i: usize, j: usize) -> bool {
     let height = trees[j][i];
 
     let mut left_indices = 0..i;
-    let mut right_indices = i + 1..trees.len();
+    let mut right_indices = i + 1..trees[j].len();
     let mut higher_indices = 0..j;
-    let mut lower_indices = j + 1..trees[i].len();
+    let mut lower_indices = j + 1..trees.len();
 
     left_indices.all(|i| trees[j][i] < height)
         || right_indices.all(|i| trees[j][i] < height)
@@ -56,7 +90,7 @@ fn is_visible(trees: &Vec<Vec<u8>>, i: usize, j: usize) -> bool {
         || lower_indices.all(|j| trees[j][i] < height)
 }
 
-fn get_scenic_score(trees: &Vec<Vec<u8>>, col: usize, row: usize) -> usize {
+fn get_scenic_score(trees: &[Vec<u8>], col: usize, row: usize) -> usize {
     let height = trees[row][col];
 
     let mut left = 0;
@@ -68,9 +102,9 @@ fn get_scenic_score(trees: &Vec<Vec<u8>>, col: usize, row: usize) -> usize {
     }
 
     let mut right = 0;
-    for col in (col + 1)..trees[0].len() {
+    for &tree in &trees[row][(col + 1)..] {
         right += 1;
-        if trees[row][col] >= height {
+        if tree >= height {
             break;
         }
     }
@@ -84,9 +118,9 @@ fn get_scenic_score(trees: &Vec<Vec<u8>>, col: usize, row: usize) -> usize {
     }
 
     let mut below = 0;
-    for row in (row + 1)..trees.len() {
+    for trees in &trees[(row + 1)..] {
         below += 1;
-        if trees[row][col] >= height {
+        if trees[col] >= height {
             break;
         }
     }
@@ -96,6 +130,7 @@ fn get_scenic_score(trees: &Vec<Vec<u8>>, col: usize, row: usize) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     fn get_input() -> Vec<Vec<u8>> {
@@ -120,15 +155,31 @@ mod tests {
             super::Solver::parse(String::from(input)).unwrap(),
             get_input()
         );
+
+        assert!(super::Solver::parse(String::from("123\n45")).is_err());
     }
 
     #[test]
     fn part_1() {
-        assert_eq!(super::Solver::part_1(get_input()).unwrap(), 21)
+        assert_eq!(super::Solver::part_1(get_input()).unwrap(), 21);
+        assert_eq!(
+            super::Solver::part_1(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap(),
+            6
+        );
     }
 
     #[test]
     fn part_2() {
         assert_eq!(super::Solver::part_2(get_input()).unwrap(), 8)
     }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<super::Solver>(|rng| {
+            let width = rng.usize(1..20);
+            rng.vec(0..20, |rng| {
+                rng.vec(width..width + 1, |rng| rng.usize(0..10) as u8)
+            })
+        });
+    }
 }
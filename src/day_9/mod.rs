@@ -1,4 +1,5 @@
-use crate::{ParseError, SolveError};
+use crate::parsing::BufRead;
+use crate::{ParseError, SolveError, Tag};
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct Point(i32, i32);
@@ -14,7 +15,7 @@ impl Point {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum Direction {
     Right,
     Left,
@@ -34,9 +35,27 @@ impl crate::Solver for Solver {
     type Input = Vec<Motion>;
     type Output = usize;
     const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const EXAMPLE: &'static str = include_str!("example.txt");
 
     fn parse(input: String) -> Result<Self::Input, ParseError> {
-        input.lines().map(|line| parse_line(line)).collect()
+        input.lines().map(parse_line).collect()
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        reader.lines().map(|line| parse_line(&line?)).collect()
+    }
+
+    fn summarise(input: &Self::Input) -> String {
+        format!(
+            "{} motions, {} steps in total",
+            input.len(),
+            input
+                .iter()
+                .map(|motion| motion.distance as usize)
+                .sum::<usize>()
+        )
     }
 
     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
@@ -79,16 +98,36 @@ impl crate::Solver for Solver {
     }
 }
 
+impl crate::Writer for Solver {
+    fn write(input: &Self::Input) -> String {
+        input
+            .iter()
+            .map(|motion| {
+                let direction = match motion.direction {
+                    Direction::Right => 'R',
+                    Direction::Left => 'L',
+                    Direction::Up => 'U',
+                    Direction::Down => 'D',
+                };
+
+                format!("{} {}\n", direction, motion.distance)
+            })
+            .collect()
+    }
+}
+
 fn parse_line(line: &str) -> Result<Motion, ParseError> {
-    let direction = match line.chars().nth(0) {
-        Some('R') => Ok(Direction::Right),
-        Some('L') => Ok(Direction::Left),
-        Some('U') => Ok(Direction::Up),
-        Some('D') => Ok(Direction::Down),
+    let (direction, distance) = line.split_once(' ').ok_or(ParseError::Invalid)?;
+
+    let direction = match direction {
+        "R" => Ok(Direction::Right),
+        "L" => Ok(Direction::Left),
+        "U" => Ok(Direction::Up),
+        "D" => Ok(Direction::Down),
         _ => Err(ParseError::Invalid),
     }?;
 
-    let distance = line[2..].parse()?;
+    let distance = distance.parse()?;
 
     Ok(Motion {
         direction,
@@ -110,6 +149,7 @@ fn move_towards(head: &Point, tail: &Point) -> Point {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assert_round_trip;
     use crate::Solver;
 
     fn get_input() -> Vec<Motion> {
@@ -164,6 +204,8 @@ R 2";
             super::Solver::parse(String::from(input)).unwrap(),
             get_input()
         );
+
+        assert!(super::Solver::parse(String::from("R")).is_err());
     }
 
     #[test]
@@ -212,4 +254,21 @@ R 2";
 
         assert_eq!(super::Solver::part_2(input).unwrap(), 36);
     }
+
+    #[test]
+    fn round_trip() {
+        let directions = [
+            Direction::Right,
+            Direction::Left,
+            Direction::Up,
+            Direction::Down,
+        ];
+
+        assert_round_trip::<super::Solver>(|rng| {
+            rng.vec(0..20, |rng| Motion {
+                direction: *rng.choose(&directions),
+                distance: rng.usize(1..20) as u8,
+            })
+        });
+    }
 }
@@ -0,0 +1,278 @@
+//! C interface to the solvers, exported from the `cdylib` build.
+//!
+//! The matching header is `include/aoc.h`; it is generated by [`header`] and
+//! checked by the test suite, so it never drifts from this file.
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use crate::{get_day, AocError, SolveError};
+
+macro_rules! define_status {
+    ($($variant:ident = $value:literal => $c_name:literal,)*) => {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum AocStatus {
+            $($variant = $value,)*
+        }
+
+        impl AocStatus {
+            /// Checks a status that foreign code filled in.
+            pub fn from_i32(value: i32) -> Option<Self> {
+                match value {
+                    $($value => Some(AocStatus::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        const STATUSES: &[(&str, i32)] = &[$(($c_name, $value),)*];
+    };
+}
+
+define_status! {
+    Ok = 0 => "AOC_OK",
+    InvalidArgument = 1 => "AOC_INVALID_ARGUMENT",
+    InvalidUtf8 = 2 => "AOC_INVALID_UTF8",
+    UnknownDay = 3 => "AOC_UNKNOWN_DAY",
+    UnknownPart = 4 => "AOC_UNKNOWN_PART",
+    InputError = 5 => "AOC_INPUT_ERROR",
+    ParseError = 6 => "AOC_PARSE_ERROR",
+    SolveError = 7 => "AOC_SOLVE_ERROR",
+    Unimplemented = 8 => "AOC_UNIMPLEMENTED",
+    Panic = 9 => "AOC_PANIC",
+}
+
+/// The result of [`aoc_solve`]. On success `value` holds the answer, otherwise
+/// it holds a description of the error. It may be null if that text could not
+/// be allocated; it must be released with [`aoc_free`] either way.
+///
+/// `status` is one of the [`AocStatus`] values, but is kept as a plain integer
+/// because plugins written in other languages may return anything.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AocAnswer {
+    pub status: i32,
+    pub value: *mut c_char,
+}
+
+impl AocAnswer {
+    pub(crate) fn new(status: AocStatus, value: String) -> Self {
+        let value = CString::new(value)
+            .map(CString::into_raw)
+            .unwrap_or(ptr::null_mut());
+
+        Self {
+            status: status as i32,
+            value,
+        }
+    }
+}
+
+impl From<AocError> for AocAnswer {
+    fn from(e: AocError) -> Self {
+        let status = match e {
+            AocError::Input(_) => AocStatus::InputError,
+            AocError::Parsing(_) => AocStatus::ParseError,
+            AocError::Plugin(_) => AocStatus::SolveError,
+            AocError::Solving(SolveError::Unimplemented) => AocStatus::Unimplemented,
+            AocError::Solving(_) => AocStatus::SolveError,
+        };
+
+        Self::new(status, e.to_string())
+    }
+}
+
+/// Solves `part` (1 or 2) of `day` for the UTF-8 input in the given buffer.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes, or be null if
+/// `input_len` is zero. The returned answer must be passed to [`aoc_free`].
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+) -> AocAnswer {
+    let input = match read_input(input_ptr, input_len) {
+        Ok(input) => input,
+        Err(answer) => return answer,
+    };
+
+    let day = match get_day(day) {
+        Some(day) => day,
+        None => return AocAnswer::new(AocStatus::UnknownDay, format!("Unknown day {}", day)),
+    };
+
+    match part {
+        1 => catch(|| day.solve_part_1(input)),
+        2 => catch(|| day.solve_part_2(input)),
+        _ => AocAnswer::new(AocStatus::UnknownPart, format!("Unknown part {}", part)),
+    }
+}
+
+pub(crate) unsafe fn read_input(
+    input_ptr: *const u8,
+    input_len: usize,
+) -> Result<String, AocAnswer> {
+    if input_ptr.is_null() && input_len != 0 {
+        return Err(AocAnswer::new(
+            AocStatus::InvalidArgument,
+            String::from("Input pointer is null"),
+        ));
+    }
+
+    let bytes = if input_len == 0 {
+        &[][..]
+    } else {
+        slice::from_raw_parts(input_ptr, input_len)
+    };
+
+    match std::str::from_utf8(bytes) {
+        Ok(input) => Ok(input.to_owned()),
+        Err(e) => Err(AocAnswer::new(AocStatus::InvalidUtf8, e.to_string())),
+    }
+}
+
+// Turns the result of f into an answer, so that neither errors nor panics
+// cross the C boundary.
+pub(crate) fn catch(f: impl FnOnce() -> Result<String, AocError>) -> AocAnswer {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(answer)) => AocAnswer::new(AocStatus::Ok, answer),
+        Ok(Err(e)) => AocAnswer::from(e),
+        Err(_) => AocAnswer::new(AocStatus::Panic, String::from("Solver panicked")),
+    }
+}
+
+/// Releases the text of an answer returned by [`aoc_solve`].
+///
+/// # Safety
+///
+/// `answer` must come from [`aoc_solve`] and must not be freed twice.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(answer: AocAnswer) {
+    if !answer.value.is_null() {
+        drop(CString::from_raw(answer.value));
+    }
+}
+
+/// Generates the contents of `include/aoc.h`.
+pub fn header() -> String {
+    let mut header = String::from(
+        "/* Generated by advent_of_code::ffi::header(). Do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+
+typedef enum AocStatus {
+",
+    );
+
+    for (name, value) in STATUSES {
+        header += &format!("    {} = {},\n", name, value);
+    }
+
+    header += "} AocStatus;
+
+typedef struct AocAnswer {
+    int32_t status;
+    char *value;
+} AocAnswer;
+
+AocAnswer aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t input_len);
+
+void aoc_free(AocAnswer answer);
+
+";
+
+    header += &format!("#define AOC_PLUGIN_ABI {}\n", crate::plugin::ABI_VERSION);
+    header += "
+typedef struct AocPlugin {
+    uint32_t abi_version;
+    uint16_t year;
+    uint8_t day;
+    const uint8_t *name_ptr;
+    size_t name_len;
+    uint32_t version;
+    uint8_t parts;
+    AocAnswer (*parse)(const uint8_t *input_ptr, size_t input_len, void **parsed);
+    AocAnswer (*solve)(void *parsed, uint8_t part);
+    AocAnswer (*summarise)(const void *parsed);
+    void (*drop)(void *parsed);
+    void (*free)(AocAnswer answer);
+} AocPlugin;
+
+/* Exported by plugins rather than by this library. */
+const AocPlugin *aoc_plugins(size_t *count);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
+";
+
+    header
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn solve(day: u8, part: u8, input: &str) -> (AocStatus, String) {
+        unsafe {
+            let answer = aoc_solve(day, part, input.as_ptr(), input.len());
+            let status = AocStatus::from_i32(answer.status).unwrap();
+            let value = CStr::from_ptr(answer.value).to_string_lossy().into_owned();
+            aoc_free(answer);
+
+            (status, value)
+        }
+    }
+
+    #[test]
+    fn statuses() {
+        let input = "1000\n2000\n\n3000";
+
+        assert_eq!(solve(1, 1, input), (AocStatus::Ok, String::from("3000")));
+        assert_eq!(solve(1, 3, input).0, AocStatus::UnknownPart);
+        assert_eq!(solve(26, 1, input).0, AocStatus::UnknownDay);
+        assert_eq!(solve(1, 1, "abc").0, AocStatus::ParseError);
+        assert_eq!(solve(25, 2, "1=").0, AocStatus::Unimplemented);
+        assert_eq!(AocStatus::from_i32(9), Some(AocStatus::Panic));
+        assert_eq!(AocStatus::from_i32(10), None);
+
+        let answer = unsafe { aoc_solve(1, 1, ptr::null(), 1) };
+        assert_eq!(answer.status, AocStatus::InvalidArgument as i32);
+        unsafe { aoc_free(answer) };
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc.h");
+
+        if std::env::var_os("AOC_UPDATE_HEADER").is_some() {
+            std::fs::write(path, header()).unwrap();
+        }
+
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            header(),
+            "include/aoc.h is out of date; rerun the tests with AOC_UPDATE_HEADER=1"
+        );
+    }
+}
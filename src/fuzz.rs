@@ -0,0 +1,162 @@
+// Feeds mutated example inputs to every implementation of every day and
+// checks that they return errors instead of panicking, and finish in time. Set
+// AOC_FUZZ_ITERATIONS to fuzz longer, and AOC_FUZZ_SEED to try other
+// mutations.
+
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::testing::{quick_params, Rng};
+use crate::{implementations, minimise, Day};
+
+const ITERATIONS: u64 = 40;
+const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+const CHARACTERS: &[u8] = b"0123456789-+=,:;.#[]<>()/* \nabcdxyzLRUDEAS";
+const NUMBERS: &[&str] = &["0", "1", "2", "3", "7", "13", "20", "99", "-1", "-7"];
+
+fn env_number(name: &str, default: u64) -> u64 {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut lines: Vec<String> = input.lines().map(|line| line.to_owned()).collect();
+
+    for _ in 0..rng.usize(1..4) {
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        let line = rng.usize(0..lines.len());
+
+        match rng.usize(0..7) {
+            0 => {
+                lines.remove(line);
+            }
+            1 => lines.insert(line, lines[line].clone()),
+            2 => {
+                let other = rng.usize(0..lines.len());
+                lines.swap(line, other);
+            }
+            3 => lines.truncate(line),
+            4 => {
+                let mut chars: Vec<char> = lines[line].chars().collect();
+
+                if !chars.is_empty() {
+                    chars.remove(rng.usize(0..chars.len()));
+                }
+
+                lines[line] = chars.into_iter().collect();
+            }
+            5 => {
+                let mut chars: Vec<char> = lines[line].chars().collect();
+                let position = rng.usize(0..chars.len() + 1);
+                chars.insert(position, *rng.choose(CHARACTERS) as char);
+                lines[line] = chars.into_iter().collect();
+            }
+            _ => lines[line] = replace_number(&lines[line], rng),
+        }
+    }
+
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+fn replace_number(line: &str, rng: &mut Rng) -> String {
+    let numbers: Vec<_> = line
+        .match_indices(|c: char| c.is_ascii_digit())
+        .map(|(start, _)| start)
+        .filter(|&start| start == 0 || !line.as_bytes()[start - 1].is_ascii_digit())
+        .collect();
+
+    if numbers.is_empty() {
+        return line.to_owned();
+    }
+
+    let start = *rng.choose(&numbers);
+    let end = line[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(line.len(), |end| start + end);
+
+    format!("{}{}{}", &line[..start], rng.choose(NUMBERS), &line[end..])
+}
+
+fn exercise(day: &Day, input: &str) {
+    let params = quick_params(day.id());
+
+    let _ = day.summarise(input.to_owned());
+    let _ = day.validate(input.to_owned());
+
+    let _ = day.solve_part_1_with(input.to_owned(), &params);
+    let _ = day.solve_part_2_with(input.to_owned(), &params);
+}
+
+fn panics(day: &Day, input: &str) -> bool {
+    panic::catch_unwind(AssertUnwindSafe(|| exercise(day, input))).is_err()
+}
+
+// Runs the day on another thread, so that a hang is reported instead of
+// blocking the test. A thread that does not finish is left behind.
+fn check(day: Day, input: String) -> Option<String> {
+    let (sender, receiver) = mpsc::channel();
+    let thread_input = input.clone();
+
+    thread::spawn(move || {
+        exercise(&day, &thread_input);
+        let _ = sender.send(());
+    });
+
+    match receiver.recv_timeout(TIME_LIMIT) {
+        Ok(()) => None,
+        Err(mpsc::RecvTimeoutError::Timeout) => Some(format!(
+            "{} ({}) did not finish within {:?} on:\n{}",
+            day.id(),
+            day.name(),
+            TIME_LIMIT,
+            input
+        )),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            let input = minimise(&input, |input| panics(&day, input)).unwrap_or(input);
+            Some(format!(
+                "{} ({}) panicked on:\n{}",
+                day.id(),
+                day.name(),
+                input
+            ))
+        }
+    }
+}
+
+#[test]
+fn fuzz() {
+    let iterations = env_number("AOC_FUZZ_ITERATIONS", ITERATIONS);
+    let seed = env_number("AOC_FUZZ_SEED", 0);
+    let mut failures = vec![];
+
+    for day in implementations() {
+        let id = day.id();
+        let example = day.example().to_owned();
+
+        let mut rng = Rng::new(seed ^ (u64::from(id.year) << 40) ^ (u64::from(id.day) << 32));
+
+        for iteration in 0..iterations {
+            let input = match iteration {
+                0 => example.clone(),
+                1 => String::new(),
+                _ => mutate(&example, &mut rng),
+            };
+
+            if let Some(failure) = check(day, input) {
+                failures.push(failure);
+                break;
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
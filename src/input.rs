@@ -1,27 +1,62 @@
 use std::env;
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
+use std::fs::File;
 use std::io;
 
+use crate::parsing::BufReader;
 use crate::AocError;
 
-pub fn get_input() -> Result<String, AocError> {
+pub fn open_input() -> Result<BufReader<File>, AocError> {
     let path = env::args_os().nth(1).ok_or(InputError::NoInputSpecified)?;
-    let input = std::fs::read_to_string(path).map_err(|e| InputError::from(e))?;
+    let file = File::open(path).map_err(InputError::from)?;
 
-    Ok(input)
+    Ok(BufReader::new(file))
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum InputError {
+    InvalidLogLevel(String),
+    InvalidPolicy(String),
+    InvalidPuzzle(String),
     Io(io::Error),
     NoInputSpecified,
+    Plugin(String),
 }
 
 impl Display for InputError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
+            InputError::InvalidLogLevel(level) => write!(
+                f,
+                "Invalid log level '{}', expected error, warn, info, debug or trace",
+                level
+            ),
+            InputError::InvalidPolicy(policy) => write!(
+                f,
+                "Invalid normalisation policy '{}', expected off, quiet, warn or strict",
+                policy
+            ),
+            InputError::InvalidPuzzle(puzzle) => {
+                write!(f, "Invalid puzzle '{}', expected <year>/<day>", puzzle)
+            }
             InputError::Io(e) => e.fmt(f),
             InputError::NoInputSpecified => write!(f, "No input file specified."),
+            InputError::Plugin(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            InputError::InvalidLogLevel(_)
+            | InputError::InvalidPolicy(_)
+            | InputError::InvalidPuzzle(_)
+            | InputError::NoInputSpecified
+            | InputError::Plugin(_) => None,
         }
     }
 }
@@ -1,67 +1,234 @@
+//! Solutions to Advent of Code 2022, usable as a library.
+//!
+//! Every day is exposed as a [`Solver`]; [`days`] lists all of them behind a
+//! type-erased [`Day`] so they can be run by number. Custom solvers implement
+//! [`Solver`] themselves and run through the same entry points:
+//!
+//! ```
+//! use advent_of_code::{ParseError, SolveError, Solver};
+//!
+//! struct Sum {}
+//!
+//! impl Solver for Sum {
+//!     type Input = Vec<u64>;
+//!     type Output = u64;
+//!     const DAY: u8 = 1;
+//!
+//!     fn parse(input: String) -> Result<Self::Input, ParseError> {
+//!         input.lines().map(|line| Ok(line.parse()?)).collect()
+//!     }
+//!
+//!     fn part_1(input: Self::Input) -> Result<Self::Output, SolveError> {
+//!         Ok(input.iter().sum())
+//!     }
+//! }
+//!
+//! let answer = advent_of_code::solve_part_1::<Sum>(String::from("1\n2\n3")).unwrap();
+//! assert_eq!(answer, 6);
+//!
+//! // Built-in days are only there if their feature is enabled.
+//! if let Some(day_1) = advent_of_code::get_day(1) {
+//!     assert_eq!(day_1.solve_part_1(String::from("1000\n\n2000")).unwrap(), "2000");
+//! }
+//! ```
+
+#[cfg(feature = "day_1")]
 pub mod day_1;
+#[cfg(feature = "day_10")]
 pub mod day_10;
+#[cfg(feature = "day_11")]
 pub mod day_11;
+#[cfg(feature = "day_12")]
 pub mod day_12;
+#[cfg(feature = "day_13")]
 pub mod day_13;
+#[cfg(feature = "day_14")]
 pub mod day_14;
+#[cfg(feature = "day_15")]
 pub mod day_15;
+#[cfg(feature = "day_16")]
 pub mod day_16;
+#[cfg(feature = "day_17")]
 pub mod day_17;
+#[cfg(feature = "day_18")]
 pub mod day_18;
+#[cfg(feature = "day_19")]
 pub mod day_19;
+#[cfg(feature = "day_2")]
 pub mod day_2;
+#[cfg(feature = "day_20")]
 pub mod day_20;
+#[cfg(feature = "day_21")]
 pub mod day_21;
+#[cfg(feature = "day_22")]
 pub mod day_22;
+#[cfg(feature = "day_23")]
 pub mod day_23;
+#[cfg(feature = "day_24")]
 pub mod day_24;
+#[cfg(feature = "day_25")]
 pub mod day_25;
+#[cfg(feature = "day_3")]
 pub mod day_3;
+#[cfg(feature = "day_4")]
 pub mod day_4;
+#[cfg(feature = "day_5")]
 pub mod day_5;
+#[cfg(feature = "day_6")]
 pub mod day_6;
+#[cfg(feature = "day_7")]
 pub mod day_7;
+#[cfg(feature = "day_8")]
 pub mod day_8;
+#[cfg(feature = "day_9")]
 pub mod day_9;
+pub mod ffi;
+pub mod log;
+pub mod parallel;
+pub mod plugin;
+pub mod year_2022;
 
+mod arithmetic;
+mod cache;
+mod cycle;
+#[cfg(test)]
+mod fuzz;
 mod input;
 mod macros;
+mod memo;
+mod minimise;
+mod normalise;
 mod parsing;
+#[cfg(any(
+    feature = "day_17",
+    feature = "day_22",
+    feature = "day_23",
+    feature = "day_24"
+))]
+mod point;
+mod puzzle;
+mod registry;
+mod simulation;
 mod solving;
+#[cfg(test)]
+mod testing;
+mod writing;
 
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 use std::time::{Duration, Instant};
 
-use input::InputError;
-use parsing::ParseError;
-use solving::{SolveError, Solver};
+use parsing::BufRead;
+
+pub use cache::Cache;
+pub use cycle::{find_cycle, Cycle};
+pub use input::InputError;
+pub use memo::{Memo, MemoStats};
+pub use minimise::{minimise, Failure};
+pub use normalise::{
+    default_policy, normalise, set_default_policy, Normalisation, NormalisePolicy, Normaliser,
+};
+pub use parsing::ParseError;
+pub use puzzle::{latest_year, PuzzleId};
+pub use registry::{days, get_day, get_implementations, get_puzzle, implementations, years, Day};
+pub use simulation::{Simulation, Simulator};
+pub use solving::{Param, Params, SolveError, Solver, Tag};
+pub use writing::Writer;
 
 pub fn run<S: Solver>() {
+    match log::Level::from_env() {
+        Ok(Some(level)) => log::set_level(level),
+        Ok(None) => (),
+        Err(e) => eprintln!("{}", e),
+    }
+
+    set_default_policy(NormalisePolicy::Warn);
+
     let start = Instant::now();
-    let part_1 = input::get_input().and_then(|input| solve_part_1::<S>(input));
+    let part_1 = input::open_input().and_then(solve_part_1_from_reader::<S, _>);
     let elapsed = start.elapsed();
     print_solve_result(S::DAY, 1, part_1, elapsed);
 
     let start = Instant::now();
-    let part_2 = input::get_input().and_then(|input| solve_part_2::<S>(input));
+    let part_2 = input::open_input().and_then(solve_part_2_from_reader::<S, _>);
     let elapsed = start.elapsed();
     print_solve_result(S::DAY, 2, part_2, elapsed);
 }
 
 pub fn solve_part_1<S: Solver>(input: String) -> Result<S::Output, AocError> {
-    let input = S::parse(input)?;
+    let input = parse::<S>(input)?;
     let result = S::part_1(input)?;
 
     Ok(result)
 }
 
 pub fn solve_part_2<S: Solver>(input: String) -> Result<S::Output, AocError> {
-    let input = S::parse(input)?;
+    let input = parse::<S>(input)?;
     let result = S::part_2(input)?;
 
     Ok(result)
 }
 
+pub fn solve_part_1_from_reader<S: Solver, R: BufRead>(reader: R) -> Result<S::Output, AocError> {
+    let input = parse_reader::<S, _>(reader)?;
+    let result = S::part_1(input)?;
+
+    Ok(result)
+}
+
+pub fn solve_part_2_from_reader<S: Solver, R: BufRead>(reader: R) -> Result<S::Output, AocError> {
+    let input = parse_reader::<S, _>(reader)?;
+    let result = S::part_2(input)?;
+
+    Ok(result)
+}
+
+pub fn solve_part_1_with<S: Solver>(input: String, params: &Params) -> Result<S::Output, AocError> {
+    check_params::<S>(params)?;
+
+    let input = parse::<S>(input)?;
+    let result = S::part_1_with(input, params)?;
+
+    Ok(result)
+}
+
+pub fn solve_part_2_with<S: Solver>(input: String, params: &Params) -> Result<S::Output, AocError> {
+    check_params::<S>(params)?;
+
+    let input = parse::<S>(input)?;
+    let result = S::part_2_with(input, params)?;
+
+    Ok(result)
+}
+
+pub(crate) fn parse<S: Solver>(input: String) -> Result<S::Input, AocError> {
+    let input = normalise(input, NormalisePolicy::from_env()?)?;
+
+    Ok(S::parse(input)?)
+}
+
+fn parse_reader<S: Solver, R: BufRead>(reader: R) -> Result<S::Input, AocError> {
+    let reader = Normaliser::new(reader, NormalisePolicy::from_env()?);
+
+    Ok(S::parse_reader(reader)?)
+}
+
+fn check_params<S: Solver>(params: &Params) -> Result<(), SolveError> {
+    match params
+        .names()
+        .find(|name| !S::PARAMS.iter().any(|param| param.name == *name))
+    {
+        Some(name) => Err(SolveError::InvalidParameter(name.to_owned())),
+        None => Ok(()),
+    }
+}
+
+pub fn canonicalise<W: Writer>(input: String) -> Result<String, AocError> {
+    let input = parse::<W>(input)?;
+
+    Ok(W::write(&input))
+}
+
 fn print_solve_result<D: Display>(
     day: u8,
     part: u8,
@@ -91,9 +258,11 @@ fn duration_to_string(duration: Duration) -> String {
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum AocError {
     Input(InputError),
     Parsing(ParseError),
+    Plugin(plugin::PluginError),
     Solving(SolveError),
 }
 
@@ -102,7 +271,19 @@ impl Display for AocError {
         match self {
             AocError::Input(e) => e.fmt(f),
             AocError::Parsing(e) => e.fmt(f),
+            AocError::Plugin(e) => e.fmt(f),
             AocError::Solving(e) => e.fmt(f),
         }
     }
 }
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Input(e) => Some(e),
+            AocError::Parsing(e) => Some(e),
+            AocError::Plugin(e) => Some(e),
+            AocError::Solving(e) => Some(e),
+        }
+    }
+}
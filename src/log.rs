@@ -0,0 +1,277 @@
+use std::cell::RefCell;
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+use crate::InputError;
+
+const LEVEL_VAR: &str = "AOC_LOG";
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn from_env() -> Result<Option<Self>, InputError> {
+        env::var(LEVEL_VAR)
+            .ok()
+            .map(|level| level.parse())
+            .transpose()
+    }
+
+    pub fn more_verbose(self) -> Self {
+        match self {
+            Level::Error => Level::Warn,
+            Level::Warn => Level::Info,
+            Level::Info => Level::Debug,
+            Level::Debug | Level::Trace => Level::Trace,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Level::Error => write!(f, "error"),
+            Level::Warn => write!(f, "warn"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(InputError::InvalidLogLevel(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub level: Level,
+    pub target: &'static str,
+    pub message: String,
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "[{} {}] {}", self.level, self.target, self.message)
+    }
+}
+
+struct Config {
+    level: Level,
+    targets: Vec<String>,
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(Config {
+    level: Level::Warn,
+    targets: Vec::new(),
+});
+
+type Sink = Arc<Mutex<Vec<Record>>>;
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+pub fn level() -> Level {
+    CONFIG
+        .read()
+        .map(|config| config.level)
+        .unwrap_or(Level::Warn)
+}
+
+pub fn set_level(level: Level) {
+    if let Ok(mut config) = CONFIG.write() {
+        config.level = level;
+    }
+}
+
+// Only logs records whose target is one of these modules or nested in one,
+// e.g. "day_19". Logs every target if empty.
+pub fn set_targets(targets: Vec<String>) {
+    if let Ok(mut config) = CONFIG.write() {
+        config.targets = targets;
+    }
+}
+
+// Runs f and returns every record it logs, whatever the level and targets.
+// This includes the records of the workers it starts with `parallel::map`.
+// Captured records are not written to stderr.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    let sink = Sink::default();
+    let result = Capture(Some(Arc::clone(&sink))).run(f);
+    let records = mem::take(&mut *lock(&sink));
+
+    (result, records)
+}
+
+// The capture of the thread it was taken on, for a worker thread to log into.
+#[derive(Clone)]
+pub(crate) struct Capture(Option<Sink>);
+
+impl Capture {
+    pub(crate) fn current() -> Self {
+        Capture(CAPTURED.with(|captured| captured.borrow().clone()))
+    }
+
+    pub(crate) fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CAPTURED.with(|captured| captured.replace(self.0.clone()));
+        let result = f();
+        CAPTURED.with(|captured| captured.replace(previous));
+
+        result
+    }
+}
+
+// Records are only pushed while the sink is locked, so it is never poisoned.
+fn lock(sink: &Sink) -> MutexGuard<'_, Vec<Record>> {
+    sink.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    if CAPTURED.with(|captured| captured.borrow().is_some()) {
+        return true;
+    }
+
+    let target = target(module_path);
+
+    CONFIG.read().is_ok_and(|config| {
+        level <= config.level
+            && (config.targets.is_empty()
+                || config.targets.iter().any(|prefix| {
+                    target == prefix
+                        || target
+                            .strip_prefix(prefix.as_str())
+                            .is_some_and(|rest| rest.starts_with("::"))
+                }))
+    })
+}
+
+pub fn emit(level: Level, module_path: &'static str, message: String) {
+    let record = Record {
+        level,
+        target: target(module_path),
+        message,
+    };
+
+    CAPTURED.with(|captured| match captured.borrow().as_ref() {
+        Some(sink) => lock(sink).push(record),
+        None => eprintln!("{}", record),
+    });
+}
+
+fn target(module_path: &str) -> &str {
+    module_path
+        .split_once("::")
+        .map_or(module_path, |(_, target)| target)
+}
+
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::emit($level, module_path!(), format!($($arg)+));
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_warn {
+    ($($arg:tt)+) => {
+        $crate::log::log!($crate::log::Level::Warn, $($arg)+)
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_info {
+    ($($arg:tt)+) => {
+        $crate::log::log!($crate::log::Level::Info, $($arg)+)
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_debug {
+    ($($arg:tt)+) => {
+        $crate::log::log!($crate::log::Level::Debug, $($arg)+)
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_trace {
+    ($($arg:tt)+) => {
+        $crate::log::log!($crate::log::Level::Trace, $($arg)+)
+    };
+}
+
+// Which of these are used depends on the days that are enabled.
+#[allow(unused_imports)]
+pub(crate) use {log, log_debug, log_info, log_trace, log_warn};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capturing() {
+        let (answer, records) = capture(|| {
+            log_info!("The answer is {}", 42);
+            42
+        });
+
+        assert_eq!(answer, 42);
+        assert_eq!(
+            records,
+            vec![Record {
+                level: Level::Info,
+                target: "log::tests",
+                message: String::from("The answer is 42"),
+            }]
+        );
+        assert_eq!(records[0].to_string(), "[info log::tests] The answer is 42");
+    }
+
+    #[test]
+    fn capturing_workers() {
+        let (_, records) = capture(|| {
+            std::thread::scope(|scope| {
+                let capture = Capture::current();
+                scope.spawn(move || capture.run(|| log_debug!("From a worker")));
+            })
+        });
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, "From a worker");
+
+        let (_, records) = capture(|| crate::parallel::map(vec![1, 2, 3], |n| log_trace!("{n}")));
+        let mut messages: Vec<_> = records.into_iter().map(|record| record.message).collect();
+        messages.sort();
+
+        assert_eq!(messages, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn levels() {
+        assert!(Level::Warn < Level::Info);
+        assert_eq!("debug".parse::<Level>().unwrap(), Level::Debug);
+        assert_eq!(Level::Debug.more_verbose(), Level::Trace);
+        assert!("loud".parse::<Level>().is_err());
+    }
+}
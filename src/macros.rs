@@ -13,7 +13,10 @@ macro_rules! define_integration_test {
         use advent_of_code::$day::Solver;
 
         fn get_input() -> String {
-            std::fs::read_to_string(format!("input/day/{}/input", $number)).unwrap()
+            let year = <Solver as advent_of_code::Solver>::YEAR;
+            let path = advent_of_code::PuzzleId::new(year, $number).input_path();
+
+            std::fs::read_to_string(path).unwrap()
         }
 
         #[test]
@@ -32,3 +35,20 @@ macro_rules! define_integration_test {
         })?
     };
 }
+
+#[macro_export]
+macro_rules! export_plugins {
+    ($($solver:ty),* $(,)?) => {
+        static AOC_PLUGINS: &[$crate::plugin::AocPlugin] =
+            &[$($crate::plugin::AocPlugin::new::<$solver>(),)*];
+
+        /// # Safety
+        ///
+        /// `count` must point to writable memory.
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugins(count: *mut usize) -> *const $crate::plugin::AocPlugin {
+            *count = AOC_PLUGINS.len();
+            AOC_PLUGINS.as_ptr()
+        }
+    };
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+// Remembers the results of a recursive search by state. The closures passed
+// to the `get_or_insert` functions receive the memo itself, so they can recurse
+// through it. A memo with a capacity stops remembering new states once it is
+// full, which bounds its memory without changing any result.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    capacity: Option<usize>,
+    stats: MemoStats,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{} hits, {} misses, {} entries",
+            self.hits, self.misses, self.entries
+        )
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            values: HashMap::new(),
+            capacity: None,
+            stats: MemoStats::default(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self
+            .capacity
+            .is_none_or(|capacity| self.values.len() < capacity)
+        {
+            self.values.insert(key, value);
+            self.stats.entries = self.values.len();
+        }
+    }
+
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    // Errors are not remembered, so the state is searched again next time.
+    pub fn try_get_or_insert_with<E, F>(&mut self, key: K, f: F) -> Result<V, E>
+    where
+        F: FnOnce(&mut Self) -> Result<V, E>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+
+        let value = f(self)?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        match n {
+            0 | 1 => n,
+            _ => memo.get_or_insert_with(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo)),
+        }
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 87,
+                misses: 89,
+                entries: 89
+            }
+        );
+        assert_eq!(memo.stats().to_string(), "87 hits, 89 misses, 89 entries");
+    }
+
+    #[test]
+    fn capacity() {
+        let mut memo = Memo::with_capacity(10);
+
+        assert_eq!(fibonacci(30, &mut memo), 832_040);
+        assert_eq!(memo.stats().entries, 10);
+    }
+
+    #[test]
+    fn errors() {
+        let mut memo: Memo<u8, u8> = Memo::new();
+
+        assert_eq!(
+            memo.try_get_or_insert_with(1, |_| Err("failed")),
+            Err("failed")
+        );
+        assert_eq!(memo.try_get_or_insert_with(1, |_| Ok::<_, ()>(2)), Ok(2));
+        assert_eq!(memo.get(&1), Some(2));
+    }
+}
@@ -0,0 +1,168 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::registry::{get_implementations, get_puzzle};
+use crate::{AocError, PuzzleId};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Failure {
+    // The reference implementation panics.
+    Panic,
+    // The reference implementation fails with an error containing this text.
+    Error(String),
+    // The implementations of the day disagree with the reference.
+    Mismatch,
+    // The reference implementation takes longer than this.
+    Slower(Duration),
+}
+
+impl Failure {
+    pub fn holds(&self, id: PuzzleId, part: u8, input: &str) -> bool {
+        let implementations = get_implementations(id);
+        let reference = match get_puzzle(id) {
+            Some(reference) => reference,
+            None => return false,
+        };
+
+        let solve = |day: &crate::Day| {
+            panic::catch_unwind(AssertUnwindSafe(|| match part {
+                1 => day.solve_part_1(input.to_owned()),
+                _ => day.solve_part_2(input.to_owned()),
+            }))
+        };
+
+        match self {
+            Failure::Panic => solve(&reference).is_err(),
+            Failure::Error(text) => {
+                matches!(solve(&reference), Ok(Err(e)) if e.to_string().contains(text.as_str()))
+            }
+            Failure::Mismatch => {
+                let expected = answer(solve(&reference));
+                implementations
+                    .iter()
+                    .any(|day| answer(solve(day)) != expected)
+            }
+            Failure::Slower(threshold) => {
+                let start = Instant::now();
+                let _ = solve(&reference);
+                start.elapsed() > *threshold
+            }
+        }
+    }
+}
+
+fn answer(result: std::thread::Result<Result<String, AocError>>) -> Option<Option<String>> {
+    result.ok().map(|answer| answer.ok())
+}
+
+// Shrinks an input while it keeps failing: first by removing blocks separated
+// by blank lines (monkeys, elves, packet pairs), then lines within each block
+// (valves, blueprints, moves). Returns None if the input does not fail at all.
+pub fn minimise(input: &str, mut fails: impl FnMut(&str) -> bool) -> Option<String> {
+    if !fails(input) {
+        return None;
+    }
+
+    let mut blocks: Vec<Vec<String>> = input
+        .trim_end_matches('\n')
+        .split("\n\n")
+        .map(|block| block.lines().map(|line| line.to_owned()).collect())
+        .collect();
+
+    blocks = ddmin(blocks, |blocks| fails(&join(blocks)));
+
+    for i in 0..blocks.len() {
+        let lines = blocks[i].clone();
+        let mut candidate = blocks.clone();
+
+        blocks[i] = ddmin(lines, |lines| {
+            candidate[i] = lines.to_vec();
+            fails(&join(&candidate))
+        });
+    }
+
+    Some(join(&blocks))
+}
+
+fn join(blocks: &[Vec<String>]) -> String {
+    let blocks: Vec<_> = blocks.iter().map(|lines| lines.join("\n")).collect();
+    format!("{}\n", blocks.join("\n\n"))
+}
+
+// Delta debugging: removes ever smaller chunks of units for as long as the
+// remaining units still fail.
+fn ddmin<T: Clone>(mut units: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+
+    while units.len() >= 2 {
+        let size = units.len().div_ceil(chunks);
+        let mut reduced = false;
+
+        for start in (0..units.len()).step_by(size) {
+            let mut complement = units[..start].to_vec();
+            complement.extend_from_slice(&units[(start + size).min(units.len())..]);
+
+            if fails(&complement) {
+                units = complement;
+                chunks = (chunks - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+
+        if !reduced {
+            if chunks >= units.len() {
+                break;
+            }
+
+            chunks = (chunks * 2).min(units.len());
+        }
+    }
+
+    if units.len() == 1 && fails(&[]) {
+        units.clear();
+    }
+
+    units
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimising() {
+        let input = "1\n2\n\n3\n13\n4\n\n5\n";
+
+        assert_eq!(
+            minimise(input, |input| input.lines().any(|line| line == "13")).unwrap(),
+            "13\n"
+        );
+        assert_eq!(
+            minimise(input, |input| input.contains('1') && input.contains('5')).unwrap(),
+            "13\n\n5\n"
+        );
+        assert_eq!(minimise(input, |input| input.contains('9')), None);
+    }
+
+    #[test]
+    fn failures() {
+        let id = PuzzleId::new(2022, 21);
+        let input = "root: pppw + sjmn\npppw: 2\nsjmn: humn * dvpt\nhumn: 5\n";
+        let failure = Failure::Error(String::from("invalid"));
+
+        assert!(failure.holds(id, 1, input));
+        assert!(!failure.holds(id, 1, &format!("{}dvpt: 3\n", input)));
+
+        let id = PuzzleId::new(2022, 5);
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n\
+                     move 1 from 2 to 1\nmove 3 from 1 to 4\nmove 2 from 2 to 1\n";
+
+        assert!(!Failure::Panic.holds(id, 1, input));
+        assert_eq!(
+            minimise(input, |input| failure.holds(id, 1, input)).unwrap(),
+            "move 2 from 2 to 1\n"
+        );
+        assert!(!Failure::Mismatch.holds(PuzzleId::new(2022, 20), 1, "1\n0\n-1\n"));
+    }
+}
@@ -0,0 +1,304 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use crate::log::log_warn;
+use crate::parsing::{BufRead, ParseError};
+use crate::InputError;
+
+const POLICY_VAR: &str = "AOC_NORMALISE";
+const BYTE_ORDER_MARK: &[u8] = b"\xef\xbb\xbf";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalisePolicy {
+    Off,
+    Quiet,
+    Warn,
+    Strict,
+}
+
+// The policy when AOC_NORMALISE is not set. The library normalises quietly and
+// the runners set it to warn.
+static DEFAULT_POLICY: RwLock<NormalisePolicy> = RwLock::new(NormalisePolicy::Quiet);
+
+impl NormalisePolicy {
+    pub fn from_env() -> Result<Self, InputError> {
+        match env::var(POLICY_VAR) {
+            Ok(policy) => policy.parse(),
+            Err(_) => Ok(default_policy()),
+        }
+    }
+}
+
+pub fn default_policy() -> NormalisePolicy {
+    DEFAULT_POLICY
+        .read()
+        .map(|policy| *policy)
+        .unwrap_or(NormalisePolicy::Quiet)
+}
+
+pub fn set_default_policy(policy: NormalisePolicy) {
+    if let Ok(mut default) = DEFAULT_POLICY.write() {
+        *default = policy;
+    }
+}
+
+impl FromStr for NormalisePolicy {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(NormalisePolicy::Off),
+            "quiet" => Ok(NormalisePolicy::Quiet),
+            "warn" => Ok(NormalisePolicy::Warn),
+            "strict" => Ok(NormalisePolicy::Strict),
+            _ => Err(InputError::InvalidPolicy(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Normalisation {
+    ByteOrderMark,
+    LineEndings,
+    TrailingWhitespace,
+    TrailingBlankLines,
+    FinalNewline,
+}
+
+impl Display for Normalisation {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Normalisation::ByteOrderMark => write!(f, "a byte order mark"),
+            Normalisation::LineEndings => write!(f, "CRLF line endings"),
+            Normalisation::TrailingWhitespace => write!(f, "trailing whitespace"),
+            Normalisation::TrailingBlankLines => write!(f, "trailing blank lines"),
+            Normalisation::FinalNewline => write!(f, "no final newline"),
+        }
+    }
+}
+
+// Rewrites its input line by line: strips a byte order mark, CRLF line endings,
+// trailing whitespace and trailing blank lines, and ends the last line with a
+// newline.
+pub struct Normaliser<R> {
+    reader: R,
+    policy: NormalisePolicy,
+    line: Vec<u8>,
+    pos: usize,
+    blank_lines: usize,
+    first_line: bool,
+    finished: bool,
+    changes: Vec<Normalisation>,
+}
+
+impl<R: BufRead> Normaliser<R> {
+    pub fn new(reader: R, policy: NormalisePolicy) -> Self {
+        Self {
+            reader,
+            policy,
+            line: vec![],
+            pos: 0,
+            blank_lines: 0,
+            first_line: true,
+            finished: false,
+            changes: vec![],
+        }
+    }
+
+    pub fn changes(&self) -> &[Normalisation] {
+        &self.changes
+    }
+
+    fn change(&mut self, change: Normalisation) -> io::Result<()> {
+        if self.policy == NormalisePolicy::Strict {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Input has {}", change),
+            ));
+        }
+
+        if !self.changes.contains(&change) {
+            self.changes.push(change);
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.finished = true;
+
+        if self.blank_lines > 0 {
+            self.change(Normalisation::TrailingBlankLines)?;
+        }
+
+        if self.policy == NormalisePolicy::Warn {
+            for change in self.changes.iter() {
+                log_warn!("Normalised input with {}", change);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn next_line(&mut self) -> io::Result<()> {
+        let mut line = vec![];
+
+        while !self.finished && line.is_empty() {
+            if self.reader.read_until(b'\n', &mut line)? == 0 {
+                return self.finish();
+            }
+
+            if self.first_line && line.starts_with(BYTE_ORDER_MARK) {
+                line.drain(..BYTE_ORDER_MARK.len());
+                self.change(Normalisation::ByteOrderMark)?;
+            }
+
+            self.first_line = false;
+
+            if line.last() == Some(&b'\n') {
+                line.pop();
+            } else {
+                self.change(Normalisation::FinalNewline)?;
+            }
+
+            if line.last() == Some(&b'\r') {
+                line.pop();
+                self.change(Normalisation::LineEndings)?;
+            }
+
+            let len = line.len();
+
+            while matches!(line.last(), Some(b' ') | Some(b'\t')) {
+                line.pop();
+            }
+
+            if line.len() < len {
+                self.change(Normalisation::TrailingWhitespace)?;
+            }
+
+            if line.is_empty() {
+                self.blank_lines += 1;
+            }
+        }
+
+        if !line.is_empty() {
+            self.line = vec![b'\n'; self.blank_lines];
+            self.line.append(&mut line);
+            self.line.push(b'\n');
+            self.pos = 0;
+            self.blank_lines = 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for Normaliser<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for Normaliser<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.policy == NormalisePolicy::Off {
+            return self.reader.fill_buf();
+        }
+
+        if self.pos == self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            self.next_line()?;
+        }
+
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        if self.policy == NormalisePolicy::Off {
+            self.reader.consume(amount);
+        } else {
+            self.pos = (self.pos + amount).min(self.line.len());
+        }
+    }
+}
+
+pub fn normalise(input: String, policy: NormalisePolicy) -> Result<String, ParseError> {
+    if policy == NormalisePolicy::Off {
+        return Ok(input);
+    }
+
+    let mut normalised = String::with_capacity(input.len() + 1);
+    Normaliser::new(input.as_bytes(), policy).read_to_string(&mut normalised)?;
+
+    Ok(normalised)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changes(input: &str) -> (String, Vec<Normalisation>) {
+        let mut normaliser = Normaliser::new(input.as_bytes(), NormalisePolicy::Quiet);
+        let mut output = String::new();
+        normaliser.read_to_string(&mut output).unwrap();
+
+        (output, normaliser.changes().to_vec())
+    }
+
+    #[test]
+    fn normalised() {
+        assert_eq!(changes("1\n\n2\n"), (String::from("1\n\n2\n"), vec![]));
+        assert_eq!(changes(""), (String::new(), vec![]));
+    }
+
+    #[test]
+    fn normalising() {
+        assert_eq!(
+            changes("\u{feff}1\r\n\r\n2 \t\r\n\n \n3"),
+            (
+                String::from("1\n\n2\n\n\n3\n"),
+                vec![
+                    Normalisation::ByteOrderMark,
+                    Normalisation::LineEndings,
+                    Normalisation::TrailingWhitespace,
+                    Normalisation::FinalNewline,
+                ]
+            )
+        );
+        assert_eq!(
+            changes("  [D]\n\n\n"),
+            (
+                String::from("  [D]\n"),
+                vec![Normalisation::TrailingBlankLines]
+            )
+        );
+    }
+
+    #[test]
+    fn policies() {
+        let input = String::from("1\r\n2");
+
+        assert_eq!(
+            normalise(input.clone(), NormalisePolicy::Quiet).unwrap(),
+            "1\n2\n"
+        );
+        assert_eq!(
+            normalise(input.clone(), NormalisePolicy::Off).unwrap(),
+            input
+        );
+        assert!(normalise(input, NormalisePolicy::Strict).is_err());
+        assert!(normalise(String::from("1\n2\n"), NormalisePolicy::Strict).is_ok());
+        assert!("loose".parse::<NormalisePolicy>().is_err());
+        assert_eq!(default_policy(), NormalisePolicy::Quiet);
+    }
+}
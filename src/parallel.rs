@@ -0,0 +1,200 @@
+// Runs independent work on a bounded number of threads. Results always come
+// back in the order of the items, whatever order the workers finish in.
+
+use std::num::NonZeroUsize;
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+
+use crate::log;
+
+// Zero means one worker per available CPU.
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+pub fn set_threads(count: usize) {
+    THREADS.store(count, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        count => count,
+    }
+}
+
+// A panic in `f` is raised again on the calling thread with its original
+// payload, once the other workers have stopped taking items.
+pub fn map<T, U, F>(items: Vec<T>, f: F) -> Vec<U>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> U + Sync,
+{
+    let workers = threads().min(items.len());
+
+    if workers <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let items: Vec<_> = items
+        .into_iter()
+        .map(|item| Mutex::new(Some(item)))
+        .collect();
+    let results: Vec<_> = items.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+    let stopped = AtomicBool::new(false);
+
+    let capture = log::Capture::current();
+
+    let work = || {
+        while !stopped.load(Ordering::Relaxed) {
+            let index = next.fetch_add(1, Ordering::Relaxed);
+
+            let item = match items.get(index) {
+                Some(item) => take(item),
+                None => break,
+            };
+
+            if let Some(item) = item {
+                let result = panic::catch_unwind(panic::AssertUnwindSafe(|| f(item)));
+
+                match result {
+                    Ok(result) => *lock(&results[index]) = Some(result),
+                    Err(payload) => {
+                        stopped.store(true, Ordering::Relaxed);
+                        panic::resume_unwind(payload);
+                    }
+                }
+            }
+        }
+    };
+
+    let panicked = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| scope.spawn(|| capture.run(work)))
+            .collect();
+
+        // Every worker is joined here, as the scope would otherwise replace
+        // the payload of a second panic with its own.
+        let panics: Vec<_> = handles
+            .into_iter()
+            .filter_map(|handle| handle.join().err())
+            .collect();
+
+        panics.into_iter().next()
+    });
+
+    if let Some(payload) = panicked {
+        panic::resume_unwind(payload);
+    }
+
+    results
+        .into_iter()
+        .map(|result| {
+            result
+                .into_inner()
+                .ok()
+                .flatten()
+                .expect("every item has a result")
+        })
+        .collect()
+}
+
+// Returns the first error in the order of the items, not the first to occur.
+pub fn try_map<T, U, E, F>(items: Vec<T>, f: F) -> Result<Vec<U>, E>
+where
+    T: Send,
+    U: Send,
+    E: Send,
+    F: Fn(T) -> Result<U, E> + Sync,
+{
+    map(items, f).into_iter().collect()
+}
+
+fn take<T>(item: &Mutex<Option<T>>) -> Option<T> {
+    lock(item).take()
+}
+
+// A worker only panics outside these locks, so they are never poisoned.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The worker count is global, so the tests that change it take turns.
+    static SETTING: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn ordered() {
+        let _setting = lock(&SETTING);
+
+        for count in [1, 2, 7] {
+            set_threads(count);
+
+            let squares = map((0..100u64).collect(), |n| n * n);
+            assert_eq!(squares, (0..100u64).map(|n| n * n).collect::<Vec<_>>());
+        }
+
+        set_threads(0);
+        assert!(threads() >= 1);
+        assert_eq!(map(Vec::<u8>::new(), |n| n), vec![]);
+    }
+
+    #[test]
+    fn first_error() {
+        let result = try_map(
+            (0..20).collect(),
+            |n: i32| if n % 7 == 6 { Err(n) } else { Ok(n) },
+        );
+
+        assert_eq!(result, Err(6));
+    }
+
+    #[test]
+    fn panics_propagate() {
+        let _setting = lock(&SETTING);
+        set_threads(4);
+
+        let result = panic::catch_unwind(|| {
+            map((0..10).collect(), |n: u8| {
+                if n == 3 {
+                    panic!("blueprint {} is broken", n);
+                }
+
+                n
+            })
+        });
+
+        set_threads(0);
+
+        let payload = result.unwrap_err();
+        assert_eq!(
+            payload
+                .downcast_ref::<String>()
+                .map(|message| message.as_str()),
+            Some("blueprint 3 is broken")
+        );
+    }
+
+    #[test]
+    fn every_item_panics() {
+        let _setting = lock(&SETTING);
+        set_threads(4);
+
+        let result = panic::catch_unwind(|| {
+            map((0..10).collect(), |n: u8| -> u8 {
+                panic!("blueprint {} is broken", n);
+            })
+        });
+
+        set_threads(0);
+
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("blueprint "), "{}", message);
+    }
+}
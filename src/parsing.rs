@@ -1,17 +1,20 @@
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 use std::io;
 use std::num::ParseIntError;
 
-pub use std::io::{BufRead, BufReader, Read};
+pub use std::io::{BufRead, BufReader};
 
 use crate::AocError;
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ParseError {
     Incomplete,
     Invalid,
     Io(io::Error),
     ParseInt(ParseIntError),
+    Plugin(String),
 }
 
 impl Display for ParseError {
@@ -21,6 +24,17 @@ impl Display for ParseError {
             ParseError::Invalid => write!(f, "Invalid input"),
             ParseError::Io(e) => write!(f, "Failed to parse input: {}", e),
             ParseError::ParseInt(e) => write!(f, "Failed to parse input: {}", e),
+            ParseError::Plugin(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io(e) => Some(e),
+            ParseError::ParseInt(e) => Some(e),
+            _ => None,
         }
     }
 }
@@ -0,0 +1,368 @@
+//! Solvers loaded from shared libraries at runtime.
+//!
+//! A plugin is a `cdylib` that exports `aoc_plugins`, returning an array of
+//! [`AocPlugin`]s. Rust plugins implement [`Solver`] and export it with
+//! [`export_plugins!`](crate::export_plugins); plugins in other languages use
+//! the declarations in `include/aoc.h`. Loaded solvers are listed by
+//! [`days`](crate::days) next to the built-in ones, or by
+//! [`implementations`](crate::implementations) if they solve a built-in day.
+
+use std::error::Error;
+use std::ffi::CStr;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::os::raw::c_void;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::slice;
+use std::{env, str};
+
+use crate::ffi::{self, AocAnswer, AocStatus};
+use crate::{
+    normalise, AocError, Day, InputError, NormalisePolicy, ParseError, SolveError, Solver,
+};
+
+pub const ABI_VERSION: u32 = 2;
+
+const PLUGIN_DIR: &str = ".aoc_plugins";
+
+/// The type-erased interface to one solver of a plugin.
+///
+/// `parse` stores the parsed input in `parsed`, which is then either consumed
+/// by `solve` or borrowed by `summarise` and released by `drop`. Every answer
+/// a plugin returns is released by its own `free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AocPlugin {
+    pub abi_version: u32,
+    pub year: u16,
+    pub day: u8,
+    pub name_ptr: *const u8,
+    pub name_len: usize,
+    pub version: u32,
+    pub parts: u8,
+    pub parse: unsafe extern "C" fn(*const u8, usize, *mut *mut c_void) -> AocAnswer,
+    pub solve: unsafe extern "C" fn(*mut c_void, u8) -> AocAnswer,
+    pub summarise: unsafe extern "C" fn(*const c_void) -> AocAnswer,
+    pub drop: unsafe extern "C" fn(*mut c_void),
+    pub free: unsafe extern "C" fn(AocAnswer),
+}
+
+// The name points to static data in the plugin, which is never unloaded.
+unsafe impl Sync for AocPlugin {}
+
+impl AocPlugin {
+    pub const fn new<S: Solver>() -> Self {
+        Self {
+            abi_version: ABI_VERSION,
+            year: S::YEAR,
+            day: S::DAY,
+            name_ptr: S::NAME.as_ptr(),
+            name_len: S::NAME.len(),
+            version: S::VERSION,
+            parts: S::PARTS,
+            parse: parse::<S>,
+            solve: solve::<S>,
+            summarise: summarise::<S>,
+            drop: drop::<S>,
+            free: ffi::aoc_free,
+        }
+    }
+
+    pub(crate) fn solve_part(&self, input: String, part: u8) -> Result<String, AocError> {
+        let parsed = self.parse(input)?;
+
+        self.take(unsafe { (self.solve)(parsed, part) })
+    }
+
+    pub(crate) fn summarise(&self, input: String) -> Result<String, AocError> {
+        let parsed = self.parse(input)?;
+        let answer = unsafe { (self.summarise)(parsed) };
+        unsafe { (self.drop)(parsed) };
+
+        self.take(answer)
+    }
+
+    fn parse(&self, input: String) -> Result<*mut c_void, AocError> {
+        let input = normalise(input, NormalisePolicy::from_env()?)?;
+        let mut parsed = ptr::null_mut();
+
+        self.take(unsafe { (self.parse)(input.as_ptr(), input.len(), &mut parsed) })?;
+
+        Ok(parsed)
+    }
+
+    // Copies the text out of an answer and hands it back to the plugin.
+    fn take(&self, answer: AocAnswer) -> Result<String, AocError> {
+        let status = answer.status;
+        let value = if answer.value.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(answer.value) }
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        unsafe { (self.free)(answer) };
+
+        match AocStatus::from_i32(status) {
+            Some(AocStatus::Ok) => Ok(value),
+            Some(AocStatus::InputError | AocStatus::InvalidUtf8) => {
+                Err(InputError::Plugin(value).into())
+            }
+            Some(AocStatus::ParseError) => Err(ParseError::Plugin(value).into()),
+            Some(AocStatus::Unimplemented) => Err(SolveError::Unimplemented.into()),
+            Some(_) => Err(PluginError::Failed(value).into()),
+            None => Err(PluginError::Status(status).into()),
+        }
+    }
+}
+
+unsafe extern "C" fn parse<S: Solver>(
+    input_ptr: *const u8,
+    input_len: usize,
+    parsed: *mut *mut c_void,
+) -> AocAnswer {
+    let input = match ffi::read_input(input_ptr, input_len) {
+        Ok(input) => input,
+        Err(answer) => return answer,
+    };
+
+    ffi::catch(|| {
+        let input = S::parse(input)?;
+        *parsed = Box::into_raw(Box::new(input)) as *mut c_void;
+
+        Ok(String::new())
+    })
+}
+
+unsafe extern "C" fn solve<S: Solver>(parsed: *mut c_void, part: u8) -> AocAnswer {
+    let input = *Box::from_raw(parsed as *mut S::Input);
+
+    match part {
+        1 => ffi::catch(|| Ok(S::part_1(input)?.to_string())),
+        2 => ffi::catch(|| Ok(S::part_2(input)?.to_string())),
+        _ => AocAnswer::new(AocStatus::UnknownPart, format!("Unknown part {}", part)),
+    }
+}
+
+unsafe extern "C" fn summarise<S: Solver>(parsed: *const c_void) -> AocAnswer {
+    let input = &*(parsed as *const S::Input);
+
+    ffi::catch(|| Ok(S::summarise(input)))
+}
+
+unsafe extern "C" fn drop<S: Solver>(parsed: *mut c_void) {
+    std::mem::drop(Box::from_raw(parsed as *mut S::Input));
+}
+
+pub fn default_dir() -> PathBuf {
+    env::var_os("AOC_PLUGIN_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(PLUGIN_DIR))
+}
+
+/// Loads every `.so` file in a directory and returns how many solvers they
+/// registered. A missing directory has no plugins.
+pub fn load_dir(dir: &Path) -> Result<usize, PluginError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(PluginError::Load(dir.to_owned(), e.to_string())),
+    };
+
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| PluginError::Load(dir.to_owned(), e.to_string()))?;
+    paths.retain(|path| path.extension().is_some_and(|extension| extension == "so"));
+    paths.sort();
+
+    paths.iter().map(|path| load(path)).sum()
+}
+
+/// Loads a plugin and registers its solvers. Plugins are never unloaded.
+#[cfg(target_os = "linux")]
+pub fn load(path: &Path) -> Result<usize, PluginError> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let error = |message: String| PluginError::Load(path.to_owned(), message);
+    let c_path = CString::new(path.as_os_str().as_bytes()).map_err(|e| error(e.to_string()))?;
+
+    let plugins: &'static [AocPlugin] = unsafe {
+        let handle = dl::dlopen(c_path.as_ptr(), dl::RTLD_NOW);
+
+        if handle.is_null() {
+            return Err(error(dl::last_error()));
+        }
+
+        let symbol = dl::dlsym(handle, b"aoc_plugins\0".as_ptr() as *const _);
+
+        if symbol.is_null() {
+            return Err(error(dl::last_error()));
+        }
+
+        let plugins: unsafe extern "C" fn(*mut usize) -> *const AocPlugin =
+            std::mem::transmute(symbol);
+        let mut count = 0;
+        let plugins = plugins(&mut count);
+
+        if plugins.is_null() || count == 0 {
+            return Ok(0);
+        }
+
+        slice::from_raw_parts(plugins, count)
+    };
+
+    let mut days = vec![];
+
+    for plugin in plugins {
+        if plugin.abi_version != ABI_VERSION {
+            return Err(PluginError::Abi(path.to_owned(), plugin.abi_version));
+        }
+
+        let name = unsafe { slice::from_raw_parts(plugin.name_ptr, plugin.name_len) };
+        let name = str::from_utf8(name).map_err(|e| error(e.to_string()))?;
+
+        days.push(Day::plugin(plugin, name));
+    }
+
+    let count = days.len();
+    days.into_iter().for_each(crate::registry::register);
+
+    Ok(count)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn load(path: &Path) -> Result<usize, PluginError> {
+    Err(PluginError::Load(
+        path.to_owned(),
+        String::from("Plugins are only supported on Linux"),
+    ))
+}
+
+#[cfg(target_os = "linux")]
+mod dl {
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_int, c_void};
+
+    pub const RTLD_NOW: c_int = 2;
+
+    #[link(name = "dl")]
+    extern "C" {
+        pub fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+        pub fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+        fn dlerror() -> *mut c_char;
+    }
+
+    pub unsafe fn last_error() -> String {
+        let error = dlerror();
+
+        if error.is_null() {
+            String::from("Unknown error")
+        } else {
+            CStr::from_ptr(error).to_string_lossy().into_owned()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PluginError {
+    Abi(PathBuf, u32),
+    Failed(String),
+    Load(PathBuf, String),
+    Status(i32),
+}
+
+impl Display for PluginError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            PluginError::Abi(path, version) => write!(
+                f,
+                "Plugin {} uses ABI version {}, expected {}",
+                path.display(),
+                version,
+                ABI_VERSION
+            ),
+            PluginError::Failed(message) => write!(f, "{}", message),
+            PluginError::Load(path, message) => {
+                write!(f, "Failed to load plugin {}: {}", path.display(), message)
+            }
+            PluginError::Status(status) => write!(f, "Plugin returned unknown status {}", status),
+        }
+    }
+}
+
+impl Error for PluginError {}
+
+impl From<PluginError> for AocError {
+    fn from(e: PluginError) -> Self {
+        AocError::Plugin(e)
+    }
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use super::*;
+    use crate::day_1;
+
+    static PLUGIN: AocPlugin = AocPlugin::new::<day_1::Solver>();
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(
+            PLUGIN
+                .solve_part(String::from("1000\n2000\n\n4000\n"), 1)
+                .unwrap(),
+            "4000"
+        );
+        assert!(matches!(
+            PLUGIN.solve_part(String::from("abc\n"), 1),
+            Err(AocError::Parsing(ParseError::Plugin(_)))
+        ));
+        assert!(PLUGIN.summarise(String::from("1\n\n2\n")).is_ok());
+    }
+
+    #[test]
+    fn statuses() {
+        unsafe extern "C" fn input_error(_: *const u8, _: usize, _: *mut *mut c_void) -> AocAnswer {
+            AocAnswer::new(AocStatus::InputError, String::from("Unreadable"))
+        }
+
+        unsafe extern "C" fn unknown(_: *const u8, _: usize, _: *mut *mut c_void) -> AocAnswer {
+            AocAnswer {
+                status: 42,
+                value: ptr::null_mut(),
+            }
+        }
+
+        let plugin = AocPlugin {
+            parse: input_error,
+            ..AocPlugin::new::<day_1::Solver>()
+        };
+        assert!(matches!(
+            plugin.solve_part(String::from("1\n"), 1),
+            Err(AocError::Input(InputError::Plugin(_)))
+        ));
+
+        let plugin = AocPlugin {
+            parse: unknown,
+            ..AocPlugin::new::<day_1::Solver>()
+        };
+        assert!(matches!(
+            plugin.solve_part(String::from("1\n"), 1),
+            Err(AocError::Plugin(PluginError::Status(42)))
+        ));
+    }
+
+    #[test]
+    fn missing_plugins() {
+        assert_eq!(load_dir(Path::new("no such directory")).unwrap(), 0);
+        assert!(matches!(
+            load(Path::new("no such plugin.so")),
+            Err(PluginError::Load(_, _))
+        ));
+    }
+}
@@ -20,8 +20,9 @@ impl Point {
 impl<P: Borrow<Point>> Add<P> for Point {
     type Output = Point;
 
-    fn add(self, rhs: P) -> Self::Output {
-        &self + rhs
+    fn add(mut self, rhs: P) -> Self::Output {
+        self += rhs;
+        self
     }
 }
 
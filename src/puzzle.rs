@@ -0,0 +1,101 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::registry::years;
+use crate::InputError;
+
+const INPUT_DIR: &str = "input";
+const LEGACY_YEAR: u16 = 2022;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl PuzzleId {
+    pub const fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.find_file("input")
+    }
+
+    pub fn answer_path(&self, part: u8) -> PathBuf {
+        self.find_file(&format!("answer_{}", part))
+    }
+
+    fn find_file(&self, name: &str) -> PathBuf {
+        let path = Path::new(INPUT_DIR)
+            .join(self.year.to_string())
+            .join("day")
+            .join(self.day.to_string())
+            .join(name);
+
+        let legacy_path = Path::new(INPUT_DIR)
+            .join("day")
+            .join(self.day.to_string())
+            .join(name);
+
+        if self.year == LEGACY_YEAR && !path.exists() && legacy_path.exists() {
+            legacy_path
+        } else {
+            path
+        }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InputError::InvalidPuzzle(s.to_owned());
+
+        match s.split_once('/') {
+            Some((year, day)) => Ok(Self::new(
+                year.parse().map_err(|_| invalid())?,
+                day.parse().map_err(|_| invalid())?,
+            )),
+            None => Ok(Self::new(latest_year(), s.parse().map_err(|_| invalid())?)),
+        }
+    }
+}
+
+pub fn latest_year() -> u16 {
+    years().into_iter().max().unwrap_or(LEGACY_YEAR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PuzzleId;
+
+    #[test]
+    fn parsing() {
+        assert_eq!(
+            "2022/17".parse::<PuzzleId>().unwrap(),
+            PuzzleId::new(2022, 17)
+        );
+        assert_eq!("17".parse::<PuzzleId>().unwrap(), PuzzleId::new(2022, 17));
+        assert!("2022/".parse::<PuzzleId>().is_err());
+        assert_eq!(PuzzleId::new(2022, 5).to_string(), "2022/5");
+    }
+
+    #[test]
+    fn paths() {
+        let id = PuzzleId::new(2015, 3);
+
+        assert_eq!(id.input_path().to_str().unwrap(), "input/2015/day/3/input");
+        assert_eq!(
+            id.answer_path(2).to_str().unwrap(),
+            "input/2015/day/3/answer_2"
+        );
+    }
+}
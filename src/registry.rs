@@ -0,0 +1,389 @@
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::plugin::AocPlugin;
+use crate::puzzle::PuzzleId;
+use crate::simulation::{Simulation, Simulator};
+use crate::{
+    solve_part_1_with, solve_part_2_with, AocError, Param, Params, SolveError, Solver, Tag,
+};
+
+type SolveFn = fn(String, &Params) -> Result<String, AocError>;
+type SummariseFn = fn(String) -> Result<String, AocError>;
+type ValidateFn = fn(String) -> Result<Vec<String>, AocError>;
+type SimulateFn = fn(String) -> Result<Simulator, AocError>;
+
+static PLUGINS: RwLock<Vec<Day>> = RwLock::new(Vec::new());
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    tags: &'static [Tag],
+    example: &'static str,
+    parts: u8,
+    name: &'static str,
+    version: u32,
+    params: &'static [Param],
+    solver: Backend,
+    simulate: Option<SimulateFn>,
+}
+
+#[derive(Clone, Copy)]
+enum Backend {
+    Builtin {
+        part_1: SolveFn,
+        part_2: SolveFn,
+        summarise: SummariseFn,
+        validate: ValidateFn,
+    },
+    Plugin(&'static AocPlugin),
+}
+
+impl Day {
+    pub const fn new<S: Solver>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            tags: S::TAGS,
+            example: S::EXAMPLE,
+            parts: S::PARTS,
+            name: S::NAME,
+            version: S::VERSION,
+            params: S::PARAMS,
+            solver: Backend::Builtin {
+                part_1: solve_part_1_to_string::<S>,
+                part_2: solve_part_2_to_string::<S>,
+                summarise: summarise::<S>,
+                validate: validate::<S>,
+            },
+            simulate: None,
+        }
+    }
+
+    pub(crate) fn plugin(plugin: &'static AocPlugin, name: &'static str) -> Self {
+        Self {
+            year: plugin.year,
+            day: plugin.day,
+            title: "",
+            tags: &[],
+            example: "",
+            parts: plugin.parts,
+            name,
+            version: plugin.version,
+            params: &[],
+            solver: Backend::Plugin(plugin),
+            simulate: None,
+        }
+    }
+
+    pub const fn simulated<S>() -> Self
+    where
+        S: Simulation + 'static,
+        S::State: 'static,
+    {
+        Self {
+            simulate: Some(Simulator::new::<S>),
+            ..Self::new::<S>()
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn id(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    pub fn tags(&self) -> &'static [Tag] {
+        self.tags
+    }
+
+    pub fn example(&self) -> &'static str {
+        self.example
+    }
+
+    // The file the example is built from, in a checkout of this crate.
+    pub fn example_path(&self) -> Option<PathBuf> {
+        match self.solver {
+            Backend::Builtin { .. } => Some(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src")
+                    .join(format!("day_{}", self.day))
+                    .join("example.txt"),
+            ),
+            Backend::Plugin(_) => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn params(&self) -> &'static [Param] {
+        self.params
+    }
+
+    pub fn solve_part_1(&self, input: String) -> Result<String, AocError> {
+        self.solve_part_1_with(input, &Params::new())
+    }
+
+    pub fn solve_part_2(&self, input: String) -> Result<String, AocError> {
+        self.solve_part_2_with(input, &Params::new())
+    }
+
+    pub fn solve_part_1_with(&self, input: String, params: &Params) -> Result<String, AocError> {
+        match self.solver {
+            Backend::Builtin { part_1, .. } => part_1(input, params),
+            Backend::Plugin(plugin) => solve_plugin(plugin, input, 1, params),
+        }
+    }
+
+    pub fn solve_part_2_with(&self, input: String, params: &Params) -> Result<String, AocError> {
+        match self.solver {
+            Backend::Builtin { part_2, .. } => part_2(input, params),
+            Backend::Plugin(plugin) => solve_plugin(plugin, input, 2, params),
+        }
+    }
+
+    pub fn summarise(&self, input: String) -> Result<String, AocError> {
+        match self.solver {
+            Backend::Builtin { summarise, .. } => summarise(input),
+            Backend::Plugin(plugin) => plugin.summarise(input),
+        }
+    }
+
+    pub fn validate(&self, input: String) -> Result<Vec<String>, AocError> {
+        match self.solver {
+            Backend::Builtin { validate, .. } => validate(input),
+            // Plugins can only tell whether the input parses.
+            Backend::Plugin(plugin) => plugin.summarise(input).map(|_| vec![]),
+        }
+    }
+
+    // Parts are written in order, so a day implements the first `parts`.
+    pub fn implements(&self, part: u8) -> bool {
+        (1..=self.parts).contains(&part)
+    }
+
+    pub fn is_plugin(&self) -> bool {
+        matches!(self.solver, Backend::Plugin(_))
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        self.simulate.is_some()
+    }
+
+    pub fn simulate(&self, input: String) -> Option<Result<Simulator, AocError>> {
+        self.simulate.map(|simulate| simulate(input))
+    }
+}
+
+fn solve_part_1_to_string<S: Solver>(input: String, params: &Params) -> Result<String, AocError> {
+    solve_part_1_with::<S>(input, params).map(|output| output.to_string())
+}
+
+fn solve_part_2_to_string<S: Solver>(input: String, params: &Params) -> Result<String, AocError> {
+    solve_part_2_with::<S>(input, params).map(|output| output.to_string())
+}
+
+fn summarise<S: Solver>(input: String) -> Result<String, AocError> {
+    let input = crate::parse::<S>(input)?;
+
+    Ok(S::summarise(&input))
+}
+
+fn validate<S: Solver>(input: String) -> Result<Vec<String>, AocError> {
+    let input = crate::parse::<S>(input)?;
+
+    Ok(S::validate(&input))
+}
+
+// Plugins cannot take parameters, as they do not describe them.
+fn solve_plugin(
+    plugin: &AocPlugin,
+    input: String,
+    part: u8,
+    params: &Params,
+) -> Result<String, AocError> {
+    match params.names().next() {
+        Some(name) => Err(SolveError::InvalidParameter(name.to_owned()).into()),
+        None => plugin.solve_part(input, part),
+    }
+}
+
+pub(crate) fn register(day: Day) {
+    if let Ok(mut plugins) = PLUGINS.write() {
+        plugins.push(day);
+    }
+}
+
+fn plugins() -> Vec<Day> {
+    PLUGINS
+        .read()
+        .map(|plugins| plugins.clone())
+        .unwrap_or_default()
+}
+
+// The built-in days, plus the first plugin for every puzzle that has none.
+pub fn days() -> Vec<Day> {
+    let mut days = crate::year_2022::days();
+
+    for plugin in plugins() {
+        if !days.iter().any(|day| day.id() == plugin.id()) {
+            days.push(plugin);
+        }
+    }
+
+    days.sort_by_key(|day| day.id());
+    days
+}
+
+// Every implementation of every day, the reference implementations first.
+pub fn implementations() -> Vec<Day> {
+    let mut implementations = days();
+    implementations.extend(crate::year_2022::implementations());
+
+    for plugin in plugins() {
+        if !implementations
+            .iter()
+            .any(|day| day.id() == plugin.id() && day.name() == plugin.name())
+        {
+            implementations.push(plugin);
+        }
+    }
+
+    implementations
+}
+
+pub fn get_implementations(id: PuzzleId) -> Vec<Day> {
+    implementations()
+        .into_iter()
+        .filter(|day| day.id() == id)
+        .collect()
+}
+
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<_> = days().iter().map(|day| day.year()).collect();
+    years.dedup();
+    years
+}
+
+pub fn get_day(day: u8) -> Option<Day> {
+    get_puzzle(PuzzleId::new(2022, day))
+}
+
+pub fn get_puzzle(id: PuzzleId) -> Option<Day> {
+    days().into_iter().find(|day| day.id() == id)
+}
+
+#[cfg(all(test, feature = "all-days"))]
+mod tests {
+    use crate::testing::quick_params;
+    use crate::{AocError, PuzzleId, SolveError};
+
+    #[test]
+    fn days_are_ordered() {
+        let days: Vec<_> = super::days().iter().map(|day| day.id()).collect();
+        let mut sorted = days.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        assert_eq!(days, sorted);
+        assert_eq!(super::years(), vec![2022]);
+    }
+
+    #[test]
+    fn solve_by_puzzle() {
+        let day = super::get_puzzle(PuzzleId::new(2022, 1)).unwrap();
+
+        assert_eq!(day.id().to_string(), "2022/1");
+        assert!(super::get_puzzle(PuzzleId::new(2021, 1)).is_none());
+    }
+
+    #[test]
+    fn implementations() {
+        let names: Vec<_> = super::get_implementations(PuzzleId::new(2022, 20))
+            .iter()
+            .map(|day| day.name())
+            .collect();
+
+        assert_eq!(names, vec!["reference", "fast"]);
+        assert_eq!(super::get_implementations(PuzzleId::new(2022, 1)).len(), 1);
+    }
+
+    #[test]
+    fn solve_by_number() {
+        let day = super::get_day(1).unwrap();
+        let answer = day
+            .solve_part_1(String::from("1000\n2000\n\n4000\n"))
+            .unwrap();
+
+        assert_eq!(answer, "4000");
+    }
+
+    #[test]
+    fn metadata() {
+        let day = super::get_day(16).unwrap();
+
+        assert_eq!(day.title(), "Proboscidea Volcanium");
+        assert_eq!(day.tags(), &[crate::Tag::Graph, crate::Tag::Search]);
+        assert_eq!(
+            std::fs::read_to_string(day.example_path().unwrap()).unwrap(),
+            day.example()
+        );
+        assert!(day.implements(1));
+        assert!(!day.implements(2));
+        assert!(!day.implements(3));
+    }
+
+    // Every declared part solves its example, and every other part returns
+    // SolveError::Unimplemented.
+    #[test]
+    fn declared_parts() {
+        for day in super::implementations() {
+            let params = quick_params(day.id());
+
+            for part in 1..=2 {
+                let example = day.example().to_owned();
+                let answer = match part {
+                    1 => day.solve_part_1_with(example, &params),
+                    _ => day.solve_part_2_with(example, &params),
+                };
+
+                assert_eq!(
+                    !matches!(answer, Err(AocError::Solving(SolveError::Unimplemented))),
+                    day.implements(part),
+                    "{} ({}) part {}",
+                    day.id(),
+                    day.name(),
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_parameter() {
+        let day = super::get_day(1).unwrap();
+        let mut params = crate::Params::new();
+        params.set("rounds", 3);
+
+        assert!(day.solve_part_1_with(String::from("1"), &params).is_err());
+    }
+}
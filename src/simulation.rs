@@ -0,0 +1,81 @@
+use crate::solving::Solver;
+use crate::AocError;
+
+pub trait Simulation: Solver {
+    type State;
+
+    fn start(input: Self::Input) -> Self::State;
+
+    // Advances the simulation by one round, or returns false once it has finished.
+    fn step(state: &mut Self::State) -> bool;
+
+    fn render(state: &Self::State) -> String;
+}
+
+trait Step {
+    fn step(&mut self) -> bool;
+
+    fn render(&self) -> String;
+}
+
+struct State<S: Simulation> {
+    state: S::State,
+}
+
+impl<S: Simulation> Step for State<S> {
+    fn step(&mut self) -> bool {
+        S::step(&mut self.state)
+    }
+
+    fn render(&self) -> String {
+        S::render(&self.state)
+    }
+}
+
+pub struct Simulator {
+    state: Box<dyn Step>,
+    round: usize,
+    finished: bool,
+}
+
+impl Simulator {
+    pub fn new<S>(input: String) -> Result<Self, AocError>
+    where
+        S: Simulation + 'static,
+        S::State: 'static,
+    {
+        let input = crate::parse::<S>(input)?;
+
+        Ok(Self {
+            state: Box::new(State::<S> {
+                state: S::start(input),
+            }),
+            round: 0,
+            finished: false,
+        })
+    }
+
+    pub fn step(&mut self) -> bool {
+        if !self.finished {
+            if self.state.step() {
+                self.round += 1;
+            } else {
+                self.finished = true;
+            }
+        }
+
+        !self.finished
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn render(&self) -> String {
+        self.state.render()
+    }
+}
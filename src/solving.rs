@@ -1,15 +1,41 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 
-use crate::parsing::ParseError;
+use crate::parsing::{BufRead, ParseError};
 use crate::AocError;
 
 pub trait Solver {
     type Input;
     type Output: Display;
+    const YEAR: u16 = 2022;
     const DAY: u8;
+    const TITLE: &'static str = "";
+    const TAGS: &'static [Tag] = &[];
+    const EXAMPLE: &'static str = "";
+    const PARTS: u8 = 2;
+    const NAME: &'static str = "reference";
+    const VERSION: u32 = 1;
+    const PARAMS: &'static [Param] = &[];
 
     fn parse(input: String) -> Result<Self::Input, ParseError>;
 
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input, ParseError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+
+        Self::parse(input)
+    }
+
+    fn summarise(_input: &Self::Input) -> String {
+        String::from("No summary available")
+    }
+
+    fn validate(_input: &Self::Input) -> Vec<String> {
+        vec![]
+    }
+
     fn part_1(_input: Self::Input) -> Result<Self::Output, SolveError> {
         Err(SolveError::Unimplemented)
     }
@@ -17,25 +43,99 @@ pub trait Solver {
     fn part_2(_input: Self::Input) -> Result<Self::Output, SolveError> {
         Err(SolveError::Unimplemented)
     }
+
+    fn part_1_with(input: Self::Input, _params: &Params) -> Result<Self::Output, SolveError> {
+        Self::part_1(input)
+    }
+
+    fn part_2_with(input: Self::Input, _params: &Params) -> Result<Self::Output, SolveError> {
+        Self::part_2(input)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+// The techniques a puzzle calls for, to find similar days.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Tag {
+    Graph,
+    Grid,
+    Math,
+    Parsing,
+    Search,
+    Simulation,
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Tag::Graph => write!(f, "graph"),
+            Tag::Grid => write!(f, "grid"),
+            Tag::Math => write!(f, "math"),
+            Tag::Parsing => write!(f, "parsing"),
+            Tag::Search => write!(f, "search"),
+            Tag::Simulation => write!(f, "simulation"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        let _ = self.0.insert(name.to_owned(), value);
+    }
+
+    pub fn get<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T, SolveError> {
+        match self.0.get(name) {
+            Some(&value) => {
+                T::try_from(value).map_err(|_| SolveError::InvalidParameter(name.to_owned()))
+            }
+            None => Ok(default),
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(|name| name.as_str())
+    }
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum SolveError {
+    DivisionByZero(&'static str),
     EmptyInput,
     InvalidInput,
+    InvalidParameter(String),
+    Overflow(&'static str),
     Unimplemented,
 }
 
 impl Display for SolveError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
+            SolveError::DivisionByZero(operation) => write!(f, "Division by zero in {}", operation),
             SolveError::EmptyInput => write!(f, "Input is empty"),
             SolveError::InvalidInput => write!(f, "Input is invalid"),
+            SolveError::InvalidParameter(name) => write!(f, "Invalid parameter {}", name),
+            SolveError::Overflow(operation) => write!(f, "Overflow in {}", operation),
             SolveError::Unimplemented => write!(f, "Not implemented"),
         }
     }
 }
 
+impl Error for SolveError {}
+
 impl From<SolveError> for AocError {
     fn from(e: SolveError) -> Self {
         AocError::Solving(e)
@@ -0,0 +1,193 @@
+// Which of these are used depends on the days that are enabled.
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::solving::Solver;
+use crate::writing::Writer;
+use crate::{Params, PuzzleId};
+
+const ROUND_TRIPS: u64 = 100;
+const SNAPSHOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots");
+
+// Keeps days with tunable puzzle constants quick on tiny inputs.
+pub fn quick_params(id: PuzzleId) -> Params {
+    let mut params = Params::new();
+
+    if id == PuzzleId::new(2022, 15) {
+        params.set("row", 10);
+        params.set("max", 20);
+    } else if id == PuzzleId::new(2022, 19) {
+        params.set("minutes", 16);
+    }
+
+    params
+}
+
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn usize(&mut self, range: Range<usize>) -> usize {
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+    }
+
+    pub fn i64(&mut self, range: Range<i64>) -> i64 {
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as i64
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.usize(0..items.len())]
+    }
+
+    pub fn vec<T>(&mut self, len: Range<usize>, mut f: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        let len = self.usize(len);
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    pub fn name(&mut self, len: Range<usize>) -> String {
+        self.vec(len, |rng| (b'a' + rng.usize(0..26) as u8) as char)
+            .into_iter()
+            .collect()
+    }
+}
+
+pub fn assert_round_trip<W>(mut generate: impl FnMut(&mut Rng) -> W::Input)
+where
+    W: Writer,
+    W::Input: Debug + PartialEq,
+{
+    for seed in 0..ROUND_TRIPS {
+        let input = generate(&mut Rng::new(seed));
+        let text = W::write(&input);
+
+        match W::parse(text.clone()) {
+            Ok(parsed) => assert_eq!(parsed, input, "written input:\n{text}"),
+            Err(e) => panic!("failed to parse written input ({}):\n{}", e, text),
+        }
+
+        match W::parse_reader(text.as_bytes()) {
+            Ok(parsed) => assert_eq!(parsed, input, "streamed input:\n{text}"),
+            Err(e) => panic!("failed to stream written input ({}):\n{}", e, text),
+        }
+    }
+}
+
+// Checks that an alternative implementation gives the same answers as the
+// reference, or fails where it fails.
+pub fn assert_agree<A, B>(mut generate: impl FnMut(&mut Rng) -> A::Input)
+where
+    A: Solver,
+    B: Solver<Input = A::Input, Output = A::Output>,
+    A::Input: Clone + Debug,
+    A::Output: Debug + PartialEq,
+{
+    for seed in 0..ROUND_TRIPS {
+        let input = generate(&mut Rng::new(seed));
+
+        assert_eq!(
+            A::part_1(input.clone()).ok(),
+            B::part_1(input.clone()).ok(),
+            "part 1 of {:?}",
+            input
+        );
+        assert_eq!(
+            A::part_2(input.clone()).ok(),
+            B::part_2(input.clone()).ok(),
+            "part 2 of {:?}",
+            input
+        );
+    }
+}
+
+// Compares a rendered output with snapshots/<name>.snap. Run the tests
+// with AOC_UPDATE_SNAPSHOTS=1 to accept the current outputs instead.
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(SNAPSHOT_DIR).join(format!("{}.snap", name));
+
+    if std::env::var_os("AOC_UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(SNAPSHOT_DIR).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => (),
+        Ok(expected) => panic!(
+            "snapshot {} differs, rerun with AOC_UPDATE_SNAPSHOTS=1 to accept it:\n{}",
+            name,
+            diff(&expected, actual)
+        ),
+        Err(e) => panic!(
+            "no snapshot {} ({}), rerun with AOC_UPDATE_SNAPSHOTS=1 to create it:\n{}",
+            name, e, actual
+        ),
+    }
+}
+
+// A line diff over the longest common subsequence of both texts.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut output = String::new();
+
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            output += &format!(" {}\n", expected[i]);
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || common[i + 1][j] >= common[i][j + 1])
+        {
+            output += &format!("-{}\n", expected[i]);
+            i += 1;
+        } else {
+            output += &format!("+{}\n", actual[j]);
+            j += 1;
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn diff() {
+        assert_eq!(
+            super::diff("a\nb\nc\n", "a\nx\nc\nd\n"),
+            " a\n-b\n+x\n c\n+d\n"
+        );
+        assert_eq!(super::diff("", "a\n"), "+a\n");
+    }
+}
@@ -0,0 +1,5 @@
+use crate::solving::Solver;
+
+pub trait Writer: Solver {
+    fn write(input: &Self::Input) -> String;
+}
@@ -0,0 +1,138 @@
+#[cfg(feature = "day_1")]
+pub use crate::day_1;
+#[cfg(feature = "day_10")]
+pub use crate::day_10;
+#[cfg(feature = "day_11")]
+pub use crate::day_11;
+#[cfg(feature = "day_12")]
+pub use crate::day_12;
+#[cfg(feature = "day_13")]
+pub use crate::day_13;
+#[cfg(feature = "day_14")]
+pub use crate::day_14;
+#[cfg(feature = "day_15")]
+pub use crate::day_15;
+#[cfg(feature = "day_16")]
+pub use crate::day_16;
+#[cfg(feature = "day_17")]
+pub use crate::day_17;
+#[cfg(feature = "day_18")]
+pub use crate::day_18;
+#[cfg(feature = "day_19")]
+pub use crate::day_19;
+#[cfg(feature = "day_2")]
+pub use crate::day_2;
+#[cfg(feature = "day_20")]
+pub use crate::day_20;
+#[cfg(feature = "day_21")]
+pub use crate::day_21;
+#[cfg(feature = "day_22")]
+pub use crate::day_22;
+#[cfg(feature = "day_23")]
+pub use crate::day_23;
+#[cfg(feature = "day_24")]
+pub use crate::day_24;
+#[cfg(feature = "day_25")]
+pub use crate::day_25;
+#[cfg(feature = "day_3")]
+pub use crate::day_3;
+#[cfg(feature = "day_4")]
+pub use crate::day_4;
+#[cfg(feature = "day_5")]
+pub use crate::day_5;
+#[cfg(feature = "day_6")]
+pub use crate::day_6;
+#[cfg(feature = "day_7")]
+pub use crate::day_7;
+#[cfg(feature = "day_8")]
+pub use crate::day_8;
+#[cfg(feature = "day_9")]
+pub use crate::day_9;
+
+use crate::Day;
+
+// Only the days whose feature is enabled are listed.
+#[allow(clippy::vec_init_then_push)]
+pub fn days() -> Vec<Day> {
+    #[allow(unused_mut)]
+    let mut days = vec![];
+
+    #[cfg(feature = "day_1")]
+    days.push(Day::new::<day_1::Solver>());
+    #[cfg(feature = "day_2")]
+    days.push(Day::new::<day_2::Solver>());
+    #[cfg(feature = "day_3")]
+    days.push(Day::new::<day_3::Solver>());
+    #[cfg(feature = "day_4")]
+    days.push(Day::new::<day_4::Solver>());
+    #[cfg(feature = "day_5")]
+    days.push(Day::new::<day_5::Solver>());
+    #[cfg(feature = "day_6")]
+    days.push(Day::new::<day_6::Solver>());
+    #[cfg(feature = "day_7")]
+    days.push(Day::new::<day_7::Solver>());
+    #[cfg(feature = "day_8")]
+    days.push(Day::new::<day_8::Solver>());
+    #[cfg(feature = "day_9")]
+    days.push(Day::new::<day_9::Solver>());
+    #[cfg(feature = "day_10")]
+    days.push(Day::new::<day_10::Solver>());
+    #[cfg(feature = "day_11")]
+    days.push(Day::new::<day_11::Solver>());
+    #[cfg(feature = "day_12")]
+    days.push(Day::new::<day_12::Solver>());
+    #[cfg(feature = "day_13")]
+    days.push(Day::new::<day_13::Solver>());
+    #[cfg(feature = "day_14")]
+    days.push(Day::simulated::<day_14::Solver>());
+    #[cfg(feature = "day_15")]
+    days.push(Day::new::<day_15::Solver>());
+    #[cfg(feature = "day_16")]
+    days.push(Day::new::<day_16::Solver>());
+    #[cfg(feature = "day_17")]
+    days.push(Day::simulated::<day_17::Solver>());
+    #[cfg(feature = "day_18")]
+    days.push(Day::new::<day_18::Solver>());
+    #[cfg(feature = "day_19")]
+    days.push(Day::new::<day_19::Solver>());
+    #[cfg(feature = "day_20")]
+    days.push(Day::new::<day_20::Solver>());
+    #[cfg(feature = "day_21")]
+    days.push(Day::new::<day_21::Solver>());
+    #[cfg(feature = "day_22")]
+    days.push(Day::simulated::<day_22::Solver>());
+    #[cfg(feature = "day_23")]
+    days.push(Day::simulated::<day_23::Solver>());
+    #[cfg(feature = "day_24")]
+    days.push(Day::simulated::<day_24::Solver>());
+    #[cfg(feature = "day_25")]
+    days.push(Day::new::<day_25::Solver>());
+
+    days
+}
+
+// Alternatives to the reference implementations in days().
+#[allow(clippy::vec_init_then_push)]
+pub fn implementations() -> Vec<Day> {
+    #[allow(unused_mut)]
+    let mut implementations = vec![];
+
+    #[cfg(feature = "day_20")]
+    implementations.push(Day::new::<day_20::fast::Solver>());
+
+    implementations
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "all-days")]
+    fn all_days() {
+        let days: Vec<_> = super::days()
+            .iter()
+            .map(|day| (day.year(), day.day()))
+            .collect();
+
+        assert_eq!(days, (1..=25).map(|day| (2022, day)).collect::<Vec<_>>());
+    }
+}